@@ -0,0 +1,273 @@
+mod report;
+mod streaming;
+
+use aoc::{Answer, Command, Solution};
+use std::collections::HashMap;
+use std::fmt::Debug;
+use std::fs::File;
+use std::hash::Hash;
+use std::io::{self, BufRead, BufReader};
+use std::str::FromStr;
+
+const DEFAULT_MEMORY_BUDGET: usize = 64 << 20;
+const DEFAULT_REPORT_ROWS: usize = 10;
+
+/// Any integer type a location list can be parsed into. Totals are accumulated as `i128`.
+trait LocationId: Copy + Ord + Hash + FromStr<Err: Debug> + Into<i128> {}
+
+impl<T: Copy + Ord + Hash + FromStr<Err: Debug> + Into<i128>> LocationId for T {}
+
+struct ColumnComparison {
+    distances: Vec<Vec<i128>>,
+    similarities: Vec<Vec<i128>>,
+}
+
+struct Day1;
+
+impl Solution for Day1 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 1;
+
+    fn part_one(input: &str) -> impl Into<Answer> {
+        part_one(input)
+    }
+
+    fn part_two(input: &str) -> Option<impl Into<Answer>> {
+        Some(part_two(input))
+    }
+
+    fn commands() -> Vec<Command> {
+        vec![
+            Command {
+                name: "matrix",
+                about: "Distance and similarity between every pair of columns",
+                run: |input, _| {
+                    print_comparison(&compare_columns(&parse_input::<i64>(input)));
+                    Ok(())
+                },
+            },
+            Command {
+                name: "report",
+                about: "Where the distance and similarity come from [--top N] [--csv]",
+                run: |input, args| report(input, args),
+            },
+            Command {
+                name: "stream",
+                about: "Both totals for a list file (or - for stdin) too large for memory [FILE] [--memory-budget BYTES]",
+                run: |_, args| stream(args),
+            },
+        ]
+    }
+}
+
+fn main() {
+    aoc::run::<Day1>();
+}
+
+fn report(input: &str, args: &[String]) -> Result<(), String> {
+    let mut top = DEFAULT_REPORT_ROWS;
+    let mut csv = false;
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--top" => top = args.next().and_then(|top| top.parse().ok()).ok_or("--top needs a number of rows")?,
+            "--csv" => csv = true,
+            other => return Err(format!("Unknown report option {}", other)),
+        }
+    }
+
+    let columns = parse_input::<i64>(input);
+    if columns.len() < 2 {
+        return Err(format!("Expected two columns of location ids, found {}", columns.len()));
+    }
+    for table in report::report(&columns[0], &columns[1], top).tables() {
+        if csv {
+            table.print_csv();
+        } else {
+            table.print();
+        }
+    }
+    Ok(())
+}
+
+fn stream(args: &[String]) -> Result<(), String> {
+    let mut path = "input.txt";
+    let mut memory_budget = DEFAULT_MEMORY_BUDGET;
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--memory-budget" => {
+                memory_budget = args.next()
+                    .and_then(|budget| budget.parse().ok())
+                    .ok_or("--memory-budget needs a number of bytes")?;
+            }
+            file => path = file,
+        }
+    }
+
+    let reader: Box<dyn BufRead> = match path {
+        "-" => Box::new(io::stdin().lock()),
+        path => Box::new(BufReader::new(File::open(path).map_err(|error| format!("{}: {}", path, error))?)),
+    };
+    let totals = streaming::stream_totals::<i64>(reader, memory_budget).map_err(|error| error.to_string())?;
+
+    println!("Total distance: {}", totals.distance);
+    println!("Similarity score: {}", totals.similarity);
+    Ok(())
+}
+
+fn part_one(input: &str) -> i128 {
+    let columns = parse_input::<i64>(input);
+    if columns.len() < 2 {
+        return 0;
+    }
+
+    total_distance(&columns[0], &columns[1])
+}
+
+fn part_two(input: &str) -> i128 {
+    let columns = parse_input::<i64>(input);
+    if columns.len() < 2 {
+        return 0;
+    }
+
+    similarity_score(&columns[0], &columns[1])
+}
+
+fn total_distance<T: LocationId>(left_hand_side: &[T], right_hand_side: &[T]) -> i128 {
+    let mut left_hand_side = left_hand_side.to_vec();
+    let mut right_hand_side = right_hand_side.to_vec();
+    left_hand_side.sort();
+    right_hand_side.sort();
+
+    left_hand_side.into_iter().zip(right_hand_side)
+        .map(|(l, r)| (l.into() - r.into()).abs())
+        .sum()
+}
+
+fn similarity_score<T: LocationId>(left_hand_side: &[T], right_hand_side: &[T]) -> i128 {
+    let right_hand_occurrences = get_right_hand_occurrences(right_hand_side);
+
+    left_hand_side.iter()
+        .map(|left| (*left).into() * *right_hand_occurrences.get(left).unwrap_or(&0) as i128)
+        .sum()
+}
+
+fn get_right_hand_occurrences<T: LocationId>(right_hand_side: &[T]) -> HashMap<T, usize> {
+    let mut occurrence_counts = HashMap::new();
+
+    right_hand_side.iter()
+        .for_each(|right|{
+            *occurrence_counts.entry(*right).or_insert(0) += 1;
+        });
+
+    occurrence_counts
+}
+
+/// Compares every column against every other. Similarity is not symmetric, so
+/// `similarities[i][j]` scores column `i`'s values by how often they appear in column `j`.
+fn compare_columns<T: LocationId>(columns: &[Vec<T>]) -> ColumnComparison {
+    let compare_each = |metric: fn(&[T], &[T]) -> i128| {
+        columns.iter()
+            .map(|left| columns.iter().map(|right| metric(left, right)).collect())
+            .collect()
+    };
+
+    ColumnComparison {
+        distances: compare_each(total_distance),
+        similarities: compare_each(similarity_score),
+    }
+}
+
+fn print_comparison(comparison: &ColumnComparison) {
+    for (title, matrix) in [("Distance", &comparison.distances), ("Similarity", &comparison.similarities)] {
+        let width = matrix.iter()
+            .flatten()
+            .map(|value| value.to_string().len())
+            .chain([title.len()])
+            .max()
+            .unwrap_or_default();
+
+        print!("{:<width$}", title);
+        (1..=matrix.len()).for_each(|column| print!("  {:>width$}", column));
+        println!();
+
+        for (row, values) in matrix.iter().enumerate() {
+            print!("{:<width$}", row + 1);
+            values.iter().for_each(|value| print!("  {:>width$}", value));
+            println!();
+        }
+        println!();
+    }
+}
+
+fn parse_input<T: LocationId>(input: &str) -> Vec<Vec<T>> {
+    let mut columns: Vec<Vec<T>> = vec!();
+
+    input.lines().filter(|line| !line.trim().is_empty()).for_each(|line| {
+        let line_parts = line.split_ascii_whitespace().collect::<Vec<&str>>();
+        if columns.len() < 2 {
+            columns = vec!(vec!(); line_parts.len());
+        }
+        assert_eq!(columns.len(), line_parts.len(), "Every line needs the same number of columns: {}", line);
+
+        line_parts.iter().zip(columns.iter_mut())
+            .for_each(|(value, column)| column.push(value.parse().unwrap()));
+    });
+
+    columns
+}
+
+#[test]
+fn small_input() {
+    let small_input = String::from("3   4
+    4   3
+    2   5
+    1   3
+    3   9
+    3   3");
+
+    assert_eq!(vec!(vec!(3,4,2,1,3,3), vec!(4,3,5,3,9,3)), parse_input::<i32>(&small_input));
+    assert_eq!(11, part_one(&small_input));
+    assert_eq!(31, part_two(&small_input));
+}
+
+#[test]
+fn empty_input_totals_nothing() {
+    assert_eq!(0, part_one(""));
+    assert_eq!(0, part_two("\n"));
+    assert!(report("", &[]).is_err());
+    assert!(report("1\n2\n", &[]).is_err());
+}
+
+#[test]
+fn a_single_column_totals_nothing() {
+    assert_eq!(0, part_one("3\n4\n"));
+    assert_eq!(0, part_two("3\n4\n"));
+}
+
+#[test]
+fn many_columns_of_any_width() {
+    let input = "3   4   1
+    4   3   3
+    2   5   3
+    1   3   9";
+
+    let columns = parse_input::<u8>(input);
+    let comparison = compare_columns(&columns);
+
+    assert_eq!(3, columns.len());
+    assert_eq!(vec!(vec!(0, 5, 6), vec!(5, 0, 7), vec!(6, 7, 0)), comparison.distances);
+    assert_eq!(vec!(vec!(10, 10, 7), vec!(10, 21, 12), vec!(7, 12, 22)), comparison.similarities);
+}
+
+#[test]
+fn totals_do_not_overflow_the_list_type() {
+    let left = [4_000_000_000u32, 1];
+    let right = [4_000_000_000u32, 4_000_000_000, 1];
+
+    assert_eq!(8_000_000_001, similarity_score(&left, &right));
+    assert_eq!(8_589_934_590, total_distance(&[u32::MAX, u32::MAX], &[0, 0]));
+}
@@ -0,0 +1,157 @@
+use crate::{get_right_hand_occurrences, LocationId};
+use std::cmp::Reverse;
+use std::collections::BTreeSet;
+use std::fmt::Display;
+
+pub struct Report<T> {
+    /// Sorted pairs with the largest distance first.
+    pub differences: Vec<(T, T, i128)>,
+    pub left_only: Vec<(T, usize)>,
+    pub right_only: Vec<(T, usize)>,
+    /// Values in both lists with a different count in each, as `(value, left count, right count)`.
+    pub multiplicity_mismatches: Vec<(T, usize, usize)>,
+    /// Each value's contribution to the similarity score, largest first.
+    pub similarity_shares: Vec<(T, i128)>,
+    pub similarity: i128,
+}
+
+pub struct Table {
+    title: &'static str,
+    headers: &'static [&'static str],
+    rows: Vec<Vec<String>>,
+}
+
+/// Explains where the distance and similarity between two lists come from, keeping the
+/// `top` largest paired differences.
+pub fn report<T: LocationId>(left_hand_side: &[T], right_hand_side: &[T], top: usize) -> Report<T> {
+    let mut left_sorted = left_hand_side.to_vec();
+    let mut right_sorted = right_hand_side.to_vec();
+    left_sorted.sort();
+    right_sorted.sort();
+
+    let mut differences = left_sorted.into_iter().zip(right_sorted)
+        .map(|(l, r)| (l, r, (l.into() - r.into()).abs()))
+        .collect::<Vec<(T, T, i128)>>();
+    differences.sort_by_key(|(_, _, distance)| Reverse(*distance));
+    differences.truncate(top);
+
+    let left_occurrences = get_right_hand_occurrences(left_hand_side);
+    let right_occurrences = get_right_hand_occurrences(right_hand_side);
+    let values = left_occurrences.keys().chain(right_occurrences.keys()).copied().collect::<BTreeSet<T>>();
+
+    let mut report = Report {
+        differences,
+        left_only: vec!(),
+        right_only: vec!(),
+        multiplicity_mismatches: vec!(),
+        similarity_shares: vec!(),
+        similarity: 0,
+    };
+
+    for value in values {
+        match (left_occurrences.get(&value), right_occurrences.get(&value)) {
+            (Some(&left), None) => report.left_only.push((value, left)),
+            (None, Some(&right)) => report.right_only.push((value, right)),
+            (Some(&left), Some(&right)) => {
+                if left != right {
+                    report.multiplicity_mismatches.push((value, left, right));
+                }
+                let contribution = value.into() * left as i128 * right as i128;
+                report.similarity += contribution;
+                report.similarity_shares.push((value, contribution));
+            }
+            (None, None) => unreachable!(),
+        }
+    }
+    report.similarity_shares.sort_by_key(|(_, contribution)| Reverse(*contribution));
+
+    report
+}
+
+impl<T: LocationId + Display> Report<T> {
+    pub fn tables(&self) -> Vec<Table> {
+        let share = |contribution: i128| match self.similarity {
+            0 => 0.0,
+            total => contribution as f64 * 100.0 / total as f64,
+        };
+
+        vec!(
+            Table {
+                title: "Largest paired differences",
+                headers: &["Left", "Right", "Distance"],
+                rows: self.differences.iter()
+                    .map(|(l, r, distance)| vec!(l.to_string(), r.to_string(), distance.to_string()))
+                    .collect(),
+            },
+            Table {
+                title: "Only in the left list",
+                headers: &["Value", "Count"],
+                rows: self.left_only.iter().map(|(value, count)| vec!(value.to_string(), count.to_string())).collect(),
+            },
+            Table {
+                title: "Only in the right list",
+                headers: &["Value", "Count"],
+                rows: self.right_only.iter().map(|(value, count)| vec!(value.to_string(), count.to_string())).collect(),
+            },
+            Table {
+                title: "Multiplicity mismatches",
+                headers: &["Value", "Left", "Right"],
+                rows: self.multiplicity_mismatches.iter()
+                    .map(|(value, left, right)| vec!(value.to_string(), left.to_string(), right.to_string()))
+                    .collect(),
+            },
+            Table {
+                title: "Similarity shares",
+                headers: &["Value", "Contribution", "Share"],
+                rows: self.similarity_shares.iter()
+                    .map(|(value, contribution)| {
+                        vec!(value.to_string(), contribution.to_string(), format!("{:.2}%", share(*contribution)))
+                    })
+                    .collect(),
+            },
+        )
+    }
+}
+
+impl Table {
+    pub fn print(&self) {
+        let widths = self.headers.iter().enumerate()
+            .map(|(column, header)| {
+                self.rows.iter().map(|row| row[column].len()).chain([header.len()]).max().unwrap_or_default()
+            })
+            .collect::<Vec<usize>>();
+        let print_row = |cells: Vec<&str>| {
+            let cells = cells.iter().zip(&widths)
+                .map(|(cell, width)| format!("{:>width$}", cell))
+                .collect::<Vec<String>>();
+            println!("{}", cells.join("  "));
+        };
+
+        println!("{} ({})", self.title, self.rows.len());
+        print_row(self.headers.to_vec());
+        self.rows.iter().for_each(|row| print_row(row.iter().map(String::as_str).collect()));
+        println!();
+    }
+
+    pub fn print_csv(&self) {
+        println!("# {}", self.title);
+        println!("{}", self.headers.join(","));
+        self.rows.iter().for_each(|row| println!("{}", row.join(",")));
+        println!();
+    }
+}
+
+#[test]
+fn report_explains_the_totals() {
+    let explained = report(&[3, 4, 2, 1, 3, 3], &[4, 3, 5, 3, 9, 3], 2);
+
+    assert_eq!(vec!((4, 9, 5), (1, 3, 2)), explained.differences);
+    assert_eq!(vec!((1, 1), (2, 1)), explained.left_only);
+    assert_eq!(vec!((5, 1), (9, 1)), explained.right_only);
+    assert_eq!(Vec::<(i32, usize, usize)>::new(), explained.multiplicity_mismatches);
+    assert_eq!(vec!((3, 27), (4, 4)), explained.similarity_shares);
+    assert_eq!(31, explained.similarity);
+
+    let explained = report(&[7, 7, 8], &[7, 8, 8], 10);
+    assert_eq!(vec!((7, 2, 1), (8, 1, 2)), explained.multiplicity_mismatches);
+}
@@ -0,0 +1,280 @@
+use crate::LocationId;
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+use std::fs::File;
+use std::io::{self, BufRead, BufReader, BufWriter, ErrorKind, Read, Write};
+use std::iter::Peekable;
+use tempfile::{NamedTempFile, TempPath};
+
+const VALUE_BYTES: usize = size_of::<i128>();
+/// Most runs read at once, so merging stays well inside the limit on open files.
+const MAX_MERGE_INPUTS: usize = 64;
+
+#[derive(Debug, PartialEq)]
+pub struct StreamTotals {
+    pub distance: i128,
+    pub similarity: i128,
+}
+
+/// One column of values, kept in memory until the budget is reached and then
+/// written out as sorted runs of little-endian `i128`s.
+struct ExternalColumn {
+    buffer: Vec<i128>,
+    capacity: usize,
+    runs: Vec<TempPath>,
+}
+
+enum Source<'a> {
+    Run(BufReader<File>),
+    Memory(std::slice::Iter<'a, i128>),
+}
+
+/// K-way merge of sorted runs of a column, smallest value first.
+struct MergedColumn<'a> {
+    sources: Vec<Source<'a>>,
+    heap: BinaryHeap<Reverse<(i128, usize)>>,
+}
+
+/// Total distance and similarity between the first two columns of `reader`, holding
+/// at most roughly `memory_budget` bytes of values in memory at once.
+pub fn stream_totals<T: LocationId>(reader: impl BufRead, memory_budget: usize) -> io::Result<StreamTotals> {
+    let capacity = (memory_budget / (2 * VALUE_BYTES)).max(1);
+    let mut left = ExternalColumn::new(capacity);
+    let mut right = ExternalColumn::new(capacity);
+
+    for line in reader.lines() {
+        let line = line?;
+        let mut values = line.split_ascii_whitespace();
+        let (Some(l), Some(r)) = (values.next(), values.next()) else {
+            if line.trim().is_empty() {
+                continue;
+            }
+            return Err(invalid_data(format!("Expected two columns: {}", line)));
+        };
+
+        left.push(parse::<T>(l)?)?;
+        right.push(parse::<T>(r)?)?;
+    }
+
+    left.finish()?;
+    right.finish()?;
+
+    Ok(StreamTotals {
+        distance: streamed_distance(left.merged()?, right.merged()?)?,
+        similarity: streamed_similarity(left.merged()?, right.merged()?)?,
+    })
+}
+
+fn parse<T: LocationId>(value: &str) -> io::Result<i128> {
+    value.parse::<T>()
+        .map(Into::into)
+        .map_err(|error| invalid_data(format!("Invalid location id {}: {:?}", value, error)))
+}
+
+fn invalid_data(message: String) -> io::Error {
+    io::Error::new(ErrorKind::InvalidData, message)
+}
+
+fn streamed_distance(left: MergedColumn, right: MergedColumn) -> io::Result<i128> {
+    let mut distance = 0;
+
+    for (l, r) in left.zip(right) {
+        distance += (l? - r?).abs();
+    }
+
+    Ok(distance)
+}
+
+/// Both columns arrive sorted, so each value's occurrences on either side are adjacent and
+/// contribute `value * left count * right count` without counting into a map.
+fn streamed_similarity(left: MergedColumn, right: MergedColumn) -> io::Result<i128> {
+    let mut left = left.peekable();
+    let mut right = right.peekable();
+    let mut similarity = 0;
+
+    while let Some(value) = left.next() {
+        let value = value?;
+        let left_count = 1 + count_while_equal(&mut left, value)?;
+
+        let mut right_count = 0;
+        while let Some(next) = right.next_if(|next| next.as_ref().map_or(true, |next| *next <= value)) {
+            let r = next?;
+            let count = 1 + count_while_equal(&mut right, r)?;
+            if r == value {
+                right_count = count;
+            }
+        }
+
+        similarity += value * left_count * right_count;
+    }
+
+    Ok(similarity)
+}
+
+fn count_while_equal(column: &mut Peekable<MergedColumn>, value: i128) -> io::Result<i128> {
+    let mut count = 0;
+    while let Some(next) = column.next_if(|next| next.as_ref().map_or(true, |next| *next == value)) {
+        next?;
+        count += 1;
+    }
+
+    Ok(count)
+}
+
+impl ExternalColumn {
+    fn new(capacity: usize) -> ExternalColumn {
+        ExternalColumn { buffer: Vec::with_capacity(capacity), capacity, runs: vec!() }
+    }
+
+    fn push(&mut self, value: i128) -> io::Result<()> {
+        self.buffer.push(value);
+        if self.buffer.len() >= self.capacity {
+            self.spill()?;
+        }
+
+        Ok(())
+    }
+
+    fn spill(&mut self) -> io::Result<()> {
+        self.buffer.sort_unstable();
+        let run = write_run(self.buffer.drain(..).map(Ok))?;
+
+        self.runs.push(run);
+        Ok(())
+    }
+
+    /// Sorts what's left in memory and merges runs together, `MAX_MERGE_INPUTS` at a time,
+    /// until they can all be read at once.
+    fn finish(&mut self) -> io::Result<()> {
+        self.buffer.sort_unstable();
+
+        while self.runs.len() > MAX_MERGE_INPUTS {
+            self.runs = self.runs.chunks(MAX_MERGE_INPUTS)
+                .map(|runs| write_run(MergedColumn::new(runs, &[])?))
+                .collect::<io::Result<Vec<TempPath>>>()?;
+        }
+
+        Ok(())
+    }
+
+    fn merged(&self) -> io::Result<MergedColumn<'_>> {
+        MergedColumn::new(&self.runs, &self.buffer)
+    }
+}
+
+/// Writes sorted values to a new temporary file, closing it once they're all written.
+fn write_run(values: impl Iterator<Item = io::Result<i128>>) -> io::Result<TempPath> {
+    let mut run = NamedTempFile::new()?;
+    let mut writer = BufWriter::new(run.as_file_mut());
+    for value in values {
+        writer.write_all(&value?.to_le_bytes())?;
+    }
+    writer.flush()?;
+    drop(writer);
+
+    Ok(run.into_temp_path())
+}
+
+impl<'a> MergedColumn<'a> {
+    fn new(runs: &[TempPath], memory: &'a [i128]) -> io::Result<MergedColumn<'a>> {
+        let mut sources = runs.iter()
+            .map(|run| File::open(run).map(|file| Source::Run(BufReader::new(file))))
+            .collect::<io::Result<Vec<Source>>>()?;
+        sources.push(Source::Memory(memory.iter()));
+
+        let mut heap = BinaryHeap::new();
+        for (index, source) in sources.iter_mut().enumerate() {
+            if let Some(value) = source.next_value()? {
+                heap.push(Reverse((value, index)));
+            }
+        }
+
+        Ok(MergedColumn { sources, heap })
+    }
+}
+
+impl Source<'_> {
+    fn next_value(&mut self) -> io::Result<Option<i128>> {
+        match self {
+            Source::Memory(values) => Ok(values.next().copied()),
+            Source::Run(reader) => {
+                let mut bytes = [0; VALUE_BYTES];
+                match reader.read_exact(&mut bytes) {
+                    Ok(()) => Ok(Some(i128::from_le_bytes(bytes))),
+                    Err(error) if error.kind() == ErrorKind::UnexpectedEof => Ok(None),
+                    Err(error) => Err(error),
+                }
+            }
+        }
+    }
+}
+
+impl Iterator for MergedColumn<'_> {
+    type Item = io::Result<i128>;
+
+    fn next(&mut self) -> Option<io::Result<i128>> {
+        let Reverse((value, index)) = self.heap.pop()?;
+
+        match self.sources[index].next_value() {
+            Ok(Some(next)) => self.heap.push(Reverse((next, index))),
+            Ok(None) => {}
+            Err(error) => return Some(Err(error)),
+        }
+
+        Some(Ok(value))
+    }
+}
+
+#[test]
+fn spilled_runs_match_the_in_memory_totals() {
+    let input = "3   4
+    4   3
+    2   5
+    1   3
+    3   9
+    3   3";
+
+    assert_eq!(StreamTotals { distance: 11, similarity: 31 }, stream_totals::<i32>(input.as_bytes(), 1).unwrap());
+    assert_eq!(StreamTotals { distance: 11, similarity: 31 }, stream_totals::<i32>(input.as_bytes(), 1 << 20).unwrap());
+}
+
+#[test]
+fn many_runs_agree_with_part_one_and_two() {
+    let mut random = aoc::Lcg::new(17);
+    let input = (0..5000)
+        .map(|_| {
+            let value = random.next_u64();
+            format!("{}   {}", (value >> 33) % 500, (value >> 45) % 500)
+        })
+        .collect::<Vec<String>>()
+        .join("\n");
+
+    let totals = stream_totals::<u16>(input.as_bytes(), 1000).unwrap();
+
+    assert_eq!(crate::part_one(&input), totals.distance);
+    assert_eq!(crate::part_two(&input), totals.similarity);
+}
+
+#[test]
+fn runs_are_merged_in_passes() {
+    let mut column = ExternalColumn::new(1);
+    for value in (0..1000).rev() {
+        column.push(value).unwrap();
+    }
+    column.push(500).unwrap();
+    column.finish().unwrap();
+
+    assert!(column.runs.len() <= MAX_MERGE_INPUTS);
+    let merged = column.merged().unwrap().collect::<io::Result<Vec<i128>>>().unwrap();
+    assert_eq!(1001, merged.len());
+    assert!(merged.windows(2).all(|pair| pair[0] <= pair[1]));
+}
+
+#[test]
+fn malformed_lines_are_errors() {
+    let error = stream_totals::<u8>("1 2\n3\n".as_bytes(), 64).unwrap_err();
+    assert_eq!(ErrorKind::InvalidData, error.kind());
+
+    let error = stream_totals::<u8>("1 2\n300 4\n".as_bytes(), 64).unwrap_err();
+    assert_eq!(ErrorKind::InvalidData, error.kind());
+}
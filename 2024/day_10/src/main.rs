@@ -1,15 +1,23 @@
+use aoc::{Answer, Solution};
 use std::collections::HashSet;
-use std::fs;
-use std::time::Instant;
 
-fn main() {
-    let input = fs::read_to_string("input.txt").expect("Could not read file");
-    
-    let start = Instant::now();
-    println!("Part One: {} - {:?}", part_one(&input), start.elapsed());
+struct Day10;
+
+impl Solution for Day10 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 10;
+
+    fn part_one(input: &str) -> impl Into<Answer> {
+        part_one(input)
+    }
 
-    let start = Instant::now();
-    println!("Part Two: {} - {:?}", part_two(&input), start.elapsed());
+    fn part_two(input: &str) -> Option<impl Into<Answer>> {
+        Some(part_two(input))
+    }
+}
+
+fn main() {
+    aoc::run::<Day10>();
 }
 
 fn part_one(input: &str) -> u32 {
@@ -102,7 +110,7 @@ fn find_trail_rating(rating: &mut u32, map: &Vec<Vec<u32>>, position: (usize, us
         .for_each(|(x, y)| find_trail_rating(rating, map, (x as usize, y as usize)));
 }
 
-fn is_within_map_bounds(map: &Vec<Vec<u32>>, starting_position: (i32, i32)) -> bool {
+fn is_within_map_bounds(map: &[Vec<u32>], starting_position: (i32, i32)) -> bool {
     starting_position.0 >= 0
         && starting_position.1 >= 0
         && starting_position.0 < map[0].len() as i32
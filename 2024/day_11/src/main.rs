@@ -1,27 +1,36 @@
+use aoc::{Answer, Solution};
 use std::collections::HashMap;
-use std::time::Instant;
 
-fn main() {
-    let input = "0 37551 469 63 1 791606 2065 9983586";
+struct Day11;
+
+impl Solution for Day11 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 11;
 
-    let start = Instant::now();
-    println!("Part One: {} - {:?}", part_one(input), start.elapsed());
+    fn part_one(input: &str) -> impl Into<Answer> {
+        part_one(input)
+    }
 
-    let start = Instant::now();
-    println!("Part Two: {} - {:?}", part_two(input), start.elapsed());
+    fn part_two(input: &str) -> Option<impl Into<Answer>> {
+        Some(part_two(input))
+    }
+}
+
+fn main() {
+    aoc::run::<Day11>();
 }
 
 fn part_one(input: &str) -> u64 { 
     parse_input(input)
         .iter()
-        .map(|stone|  blink_n_times(&mut vec!(*stone), 25))
+        .map(|stone|  blink_n_times(&[*stone], 25))
         .sum()
 }
 
 fn part_two(input: &str) -> u64 {
     parse_input(input)
         .iter()
-        .map(|stone|  blink_n_times(&mut vec!(*stone), 75))
+        .map(|stone|  blink_n_times(&[*stone], 75))
         .sum()
 }
 
@@ -32,18 +41,18 @@ fn parse_input(input: &str) -> Vec<(u64, u64)> {
         .collect()
 }
 
-fn blink_n_times(stones: &mut Vec<(u64, u64)>, blinks: usize) -> u64 {
+fn blink_n_times(stones: &[(u64, u64)], blinks: usize) -> u64 {
     let known_conversions = HashMap::new();
     
-    let mut new_stones = stones.clone();
+    let mut new_stones = stones.to_vec();
     for _ in 0..blinks {
-        new_stones = blink(&mut new_stones, known_conversions.clone());
+        new_stones = blink(&new_stones, known_conversions.clone());
     }
     
     new_stones.iter().map(|(_, count)| *count).sum()
 }
 
-fn blink(stones: &mut Vec<(u64, u64)>, mut known_conversions: HashMap<u64, Vec<u64>>) -> Vec<(u64, u64)>{
+fn blink(stones: &[(u64, u64)], mut known_conversions: HashMap<u64, Vec<u64>>) -> Vec<(u64, u64)>{
     let mut map = HashMap::new();
     
     for (stone, count) in stones.iter() {
@@ -92,7 +101,7 @@ fn get_new_value(stone: u64) -> Vec<u64>{
 fn small_input() {
     let result = parse_input("125 17")
         .iter()
-        .map(|stone|  blink_n_times(&mut vec!(*stone), 6))
+        .map(|stone|  blink_n_times(&[*stone], 6))
         .sum::<u64>();
     
     assert_eq!(22, result);
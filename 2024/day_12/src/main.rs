@@ -1,17 +1,25 @@
+use aoc::{Answer, Solution};
 use std::collections::HashSet;
-use std::fs;
-use std::time::Instant;
 
 type Region = (char, HashSet<(usize, usize)>);
 
-fn main() {
-    let input = fs::read_to_string("input.txt").expect("Could not read file");
+struct Day12;
+
+impl Solution for Day12 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 12;
 
-    let start = Instant::now();
-    println!("Part One: {} - {:?}", part_one(&input), start.elapsed());
+    fn part_one(input: &str) -> impl Into<Answer> {
+        part_one(input)
+    }
 
-    let start = Instant::now();
-    println!("Part Two: {} - {:?}", part_two(&input), start.elapsed());
+    fn part_two(input: &str) -> Option<impl Into<Answer>> {
+        Some(part_two(input))
+    }
+}
+
+fn main() {
+    aoc::run::<Day12>();
 }
 
 fn part_one(input: &str) -> usize {
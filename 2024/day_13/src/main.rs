@@ -1,5 +1,4 @@
-use std::fs;
-use std::time::Instant;
+use aoc::{Answer, Solution};
 use rayon::prelude::*;
 
 struct Game {
@@ -21,30 +20,23 @@ impl Game {
     }
 }
 
-#[derive(Debug)]
-struct Solution {
-    a: i64,
-    b: i64,
-}
+struct Day13;
 
-impl Solution {
-    fn new(lead_button: char, a: i64, b: i64) -> Solution {
-        if lead_button == 'a' {
-            Solution { a, b }
-        } else {
-            Solution { a: b, b: a }
-        }
+impl Solution for Day13 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 13;
+
+    fn part_one(input: &str) -> impl Into<Answer> {
+        part_one(input)
+    }
+
+    fn part_two(input: &str) -> Option<impl Into<Answer>> {
+        Some(part_two(input))
     }
 }
 
 fn main() {
-    let input = fs::read_to_string("input.txt").expect("Could not read file");
-
-    let start = Instant::now();
-    println!("Part One: {} - {:?}", part_one(&input), start.elapsed());
-
-    let start = Instant::now();
-    println!("Part Two: {} - {:?}", part_two(&input), start.elapsed());
+    aoc::run::<Day13>();
 }
 
 fn part_one(input: &str) -> i64 {
@@ -1,20 +1,27 @@
-use std::fs;
-use std::time::Instant;
+use aoc::{Answer, Solution};
 
 struct Guard {
     start_position: (usize, usize),
     velocity: (i32, i32)
 }
 
-fn main() {
-    let input = fs::read_to_string("input.txt").expect("Could not read file");
-    let grid = vec![vec![0; 101];103];
-    
-    let start = Instant::now();
-    println!("Part One: {} - {:?}", part_one(&input, &grid), start.elapsed());
+struct Day14;
+
+impl Solution for Day14 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 14;
+
+    fn part_one(input: &str) -> impl Into<Answer> {
+        part_one(input, &vec![vec![0; 101]; 103])
+    }
 
-    let start = Instant::now();
-    println!("Part Two: {} - {:?}", part_two(&input, &grid), start.elapsed());
+    fn part_two(input: &str) -> Option<impl Into<Answer>> {
+        Some(part_two(input, &vec![vec![0; 101]; 103]))
+    }
+}
+
+fn main() {
+    aoc::run::<Day14>();
 }
 
 fn part_one(input: &str, grid: &[Vec<u32>]) -> usize {
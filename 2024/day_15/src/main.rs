@@ -1,5 +1,4 @@
-use std::fs;
-use std::time::Instant;
+use aoc::{Answer, Solution};
 
 #[derive(Debug)]
 enum Direction {
@@ -21,11 +20,19 @@ impl Direction {
     }
 }
 
-fn main() {
-    let input = fs::read_to_string("input.txt").unwrap();
+struct Day15;
+
+impl Solution for Day15 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 15;
 
-    let start = Instant::now();
-    println!("Part One: {} - {:?}", part_one(&input), start.elapsed());
+    fn part_one(input: &str) -> impl Into<Answer> {
+        part_one(input)
+    }
+}
+
+fn main() {
+    aoc::run::<Day15>();
 }
 
 fn part_one(input: &str) -> usize {
@@ -80,10 +87,10 @@ fn robot_can_move(path_ahead: &[char]) -> bool {
 }
 
 fn move_robot(
-    map: &mut Vec<Vec<char>>,
+    map: &mut [Vec<char>],
     location: &mut (usize, usize),
     direction: &Direction,
-    path_ahead: &Vec<char>,
+    path_ahead: &[char],
 ) {
     let new_path = get_moved_path(path_ahead);
     map[location.1][location.0] = '.';
@@ -118,7 +125,7 @@ fn move_robot(
     }
 }
 
-fn get_moved_path(path_ahead: &Vec<char>) -> Vec<char> {
+fn get_moved_path(path_ahead: &[char]) -> Vec<char> {
     let first_blocker = path_ahead.iter().position(|block| *block == '#').unwrap();
     let mut blocks_to_change = path_ahead[0..first_blocker].to_vec();
     if blocks_to_change[0] == 'O' {
@@ -177,8 +184,8 @@ fn part_test() {
 
 <^^>>>vv<v>>v<<";
 
-    let (mut map, _) = parse_input(input);
-    let mut robot_location = find_robot_location(&map);
+    let (map, _) = parse_input(input);
+    let robot_location = find_robot_location(&map);
     assert_eq!((2, 2), robot_location);
     assert_eq!(
         vec!('#', '#'),
@@ -1,10 +1,12 @@
+use aoc::{Answer, Implementation, Solution};
 use crate::Direction::{East, North, South, West};
 use itertools::Itertools;
-use std::collections::HashMap;
-use std::fs;
-use std::time::Instant;
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap, HashSet};
 
-#[derive(Clone, Debug)]
+type State = ((usize, usize), Direction);
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
 enum Direction {
     North,
     East,
@@ -49,14 +51,38 @@ impl Path {
     }
 }
 
-fn main() {
-    let input = fs::read_to_string("input.txt").unwrap();
+struct Day16;
 
-    let start = Instant::now();
-    println!("Part One: {} - {:?}", part_one(&input), start.elapsed());
+impl Solution for Day16 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 16;
+
+    fn part_one(input: &str) -> impl Into<Answer> {
+        part_one(input)
+    }
 
-    let start = Instant::now();
-    println!("Part Two: {} - {:?}", part_two(&input), start.elapsed());
+    fn part_two(input: &str) -> Option<impl Into<Answer>> {
+        Some(part_two(input))
+    }
+
+    fn alternatives() -> Vec<Implementation> {
+        vec![
+            Implementation {
+                name: "dijkstra",
+                part: 1,
+                solve: |input| part_one_dijkstra(input).into(),
+            },
+            Implementation {
+                name: "dijkstra",
+                part: 2,
+                solve: |input| part_two_dijkstra(input).into(),
+            },
+        ]
+    }
+}
+
+fn main() {
+    aoc::run::<Day16>();
 }
 
 fn part_one(input: &str) -> usize {
@@ -88,6 +114,82 @@ fn part_two(input: &str) -> usize {
     unique_tiles.len()
 }
 
+fn part_one_dijkstra(input: &str) -> usize {
+    let map = parse_input(input);
+    let (costs, _) = find_cheapest_routes(&map);
+
+    end_states(&map, &costs).map(|state| costs[&state]).min().unwrap()
+}
+
+fn part_two_dijkstra(input: &str) -> usize {
+    let map = parse_input(input);
+    let (costs, previous_states) = find_cheapest_routes(&map);
+    let cheapest = end_states(&map, &costs).map(|state| costs[&state]).min().unwrap();
+
+    let mut to_visit: Vec<State> = end_states(&map, &costs)
+        .filter(|state| costs[state] == cheapest)
+        .collect();
+    let mut seen: HashSet<State> = to_visit.iter().copied().collect();
+
+    while let Some(state) = to_visit.pop() {
+        for previous in previous_states.get(&state).into_iter().flatten() {
+            if seen.insert(*previous) {
+                to_visit.push(*previous);
+            }
+        }
+    }
+
+    seen.iter().map(|(location, _)| location).unique().count()
+}
+
+/// Dijkstra over (location, facing), where stepping forward costs 1 and turning on the spot costs 1000.
+/// Returns the cheapest cost of every reachable state and, for each, all states that reach it that cheaply.
+fn find_cheapest_routes(map: &[Vec<char>]) -> (HashMap<State, usize>, HashMap<State, Vec<State>>) {
+    let start = (find_start(map), East);
+    let mut costs = HashMap::from([(start, 0)]);
+    let mut previous_states: HashMap<State, Vec<State>> = HashMap::new();
+    let mut queue = BinaryHeap::from([Reverse((0, start))]);
+
+    while let Some(Reverse((cost, state))) = queue.pop() {
+        if costs.get(&state).is_some_and(|&best| best < cost) {
+            continue;
+        }
+
+        let (location, direction) = state;
+        let ahead = direction.get_location_in_direction(location);
+        let mut moves = vec![];
+        if map[ahead.1][ahead.0] != '#' {
+            moves.push((cost + 1, (ahead, direction)));
+        }
+        for turn in direction.get_turn_directions() {
+            moves.push((cost + 1000, (location, turn)));
+        }
+
+        for (new_cost, new_state) in moves {
+            match costs.get(&new_state) {
+                Some(&best) if best < new_cost => {}
+                Some(&best) if best == new_cost => {
+                    previous_states.entry(new_state).or_default().push(state);
+                }
+                _ => {
+                    costs.insert(new_state, new_cost);
+                    previous_states.insert(new_state, vec![state]);
+                    queue.push(Reverse((new_cost, new_state)));
+                }
+            }
+        }
+    }
+
+    (costs, previous_states)
+}
+
+fn end_states<'a>(map: &'a [Vec<char>], costs: &'a HashMap<State, usize>) -> impl Iterator<Item = State> + 'a {
+    costs
+        .keys()
+        .filter(|(location, _)| map[location.1][location.0] == 'E')
+        .copied()
+}
+
 fn find_paths(map: &Vec<Vec<char>>) -> Vec<(usize, Vec<(usize, usize)>)> {
     let mut start_path = Path::new(East, find_start(map));
     let mut solutions = vec![];
@@ -158,7 +260,7 @@ fn make_turns_if_available(
     for direction in path.current_direction.get_turn_directions().iter() {
         if location_worth_visiting(direction, map, &visited_locations, &path) {
             let mut new_path = Path {
-                current_direction: direction.clone(),
+                current_direction: *direction,
                 running_score: path.running_score + 1001,
                 current_location: direction.get_location_in_direction(path.current_location),
                 path_tiles: path.path_tiles.clone(),
@@ -229,6 +331,8 @@ fn small_input() {
 
     assert_eq!(7036, part_one(input));
     assert_eq!(45, part_two(input));
+    assert_eq!(7036, part_one_dijkstra(input));
+    assert_eq!(45, part_two_dijkstra(input));
 }
 
 #[test]
@@ -253,4 +357,6 @@ fn bigger_input() {
 
     assert_eq!(11048, part_one(input));
     assert_eq!(64, part_two(input));
+    assert_eq!(11048, part_one_dijkstra(input));
+    assert_eq!(64, part_two_dijkstra(input));
 }
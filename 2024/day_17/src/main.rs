@@ -1,7 +1,6 @@
 extern crate core;
 
-use std::fs;
-use std::time::Instant;
+use aoc::{Answer, Solution};
 
 #[derive(Clone)]
 struct Device {
@@ -69,23 +68,32 @@ impl Device {
     }
 }
 
-fn main() {
-    let input = fs::read_to_string("input.txt").expect("Unable to read file");
+struct Day17;
+
+impl Solution for Day17 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 17;
 
-    let start = Instant::now();
-    println!("Part One: {} - {:?}", part_one(&input), start.elapsed());
+    fn part_one(input: &str) -> impl Into<Answer> {
+        part_one(input)
+    }
 
-    let start = Instant::now();
-    println!("Part Two: {} - {:?}", part_two(&input), start.elapsed());
+    fn part_two(input: &str) -> Option<impl Into<Answer>> {
+        Some(part_two(input))
+    }
+}
+
+fn main() {
+    aoc::run::<Day17>();
 }
 
-fn part_one(input: &String) -> String {
+fn part_one(input: &str) -> String {
     let (mut device, program) = parse_input(input);
 
     run_program_on_device(&mut device, &program)
 }
 
-fn part_two(input: &String) -> u64 {
+fn part_two(input: &str) -> u64 {
     let (_, program) = parse_input(input);
     
     find_smallest_reflective_registry(&program)
@@ -93,7 +101,7 @@ fn part_two(input: &String) -> u64 {
 
 fn find_smallest_reflective_registry(program: &[u64]) -> u64 {
     let mut solutions = Vec::new();
-    find_possible_solutions(&mut solutions, &program, 1);
+    find_possible_solutions(&mut solutions, program, 1);
     
     *solutions.iter().min().unwrap()
 }
@@ -113,8 +121,7 @@ fn find_possible_solutions(solutions: &mut Vec<u64>, program: &[u64], running_po
 
 fn output_fits_end(device: &mut Device, program: &[u64]) -> bool {
     program.iter().rev().zip(device.values_to_output.iter().rev())
-        .map(|(a, b)| a == b)
-        .all(|a| a)
+        .all(|(a, b)| a == b)
 }
 
 
@@ -129,7 +136,7 @@ fn run_program_on_device(device: &mut Device, program: &[u64]) -> String {
     device.output_values()
 }
 
-fn parse_input(input: &String) -> (Device, Vec<u64>) {
+fn parse_input(input: &str) -> (Device, Vec<u64>) {
     let parts: Vec<&str> = input
         .split(['\r', '\n', ':'])
         .map(str::trim)
@@ -1,17 +1,51 @@
-use std::fs;
-use std::time::Instant;
+use aoc::{Answer, Implementation, Solution};
+use std::collections::VecDeque;
 
-fn main() {
-    let input = fs::read_to_string("input.txt").expect("Could not read file");
+type PathFinder = fn(&[Vec<char>]) -> usize;
+
+struct Day18;
 
-    let start = Instant::now();
-    println!("Part One: {} - {:?}", part_one(&input), start.elapsed());
+impl Solution for Day18 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 18;
+
+    fn part_one(input: &str) -> impl Into<Answer> {
+        part_one(input)
+    }
 
-    let start = Instant::now();
-    println!("Part Two: {:?} - {:?}", part_two(&input), start.elapsed());
+    fn part_two(input: &str) -> Option<impl Into<Answer>> {
+        Some(part_two(input))
+    }
+
+    fn alternatives() -> Vec<Implementation> {
+        vec![
+            Implementation {
+                name: "bfs",
+                part: 1,
+                solve: |input| shortest_path_after_falling_ram(input, find_shortest_path_bfs).into(),
+            },
+            Implementation {
+                name: "bfs",
+                part: 2,
+                solve: |input| first_blocking_ram(input, find_shortest_path_bfs).into(),
+            },
+        ]
+    }
+}
+
+fn main() {
+    aoc::run::<Day18>();
 }
 
 fn part_one(input: &str) -> usize {
+    shortest_path_after_falling_ram(input, find_shortest_path)
+}
+
+fn part_two(input: &str) -> (usize, usize) {
+    first_blocking_ram(input, find_shortest_path)
+}
+
+fn shortest_path_after_falling_ram(input: &str, find_shortest_path: PathFinder) -> usize {
     let mut map = vec![vec!['.'; 71]; 71];
     let ram_sequence = parse_input(input);
     make_n_ram_fall(&mut map, 1024, &ram_sequence);
@@ -19,14 +53,18 @@ fn part_one(input: &str) -> usize {
     find_shortest_path(&map)
 }
 
-fn part_two(input: &str) -> (usize, usize) {
+fn first_blocking_ram(input: &str, find_shortest_path: PathFinder) -> (usize, usize) {
     let mut map = vec![vec!['.'; 71]; 71];
     let ram_sequence = parse_input(input);
 
-    find_first_blocker(&mut map, &ram_sequence)
+    find_first_blocker(&mut map, &ram_sequence, find_shortest_path)
 }
 
-fn find_first_blocker(map: &mut [Vec<char>], ram_sequence: &[(usize, usize)]) -> (usize, usize) {
+fn find_first_blocker(
+    map: &mut [Vec<char>],
+    ram_sequence: &[(usize, usize)],
+    find_shortest_path: PathFinder,
+) -> (usize, usize) {
     for falling_ram in ram_sequence {
         map[falling_ram.1][falling_ram.0] = '#';
     }
@@ -56,8 +94,7 @@ fn parse_input(input: &str) -> Vec<(usize, usize)> {
 }
 
 fn make_n_ram_fall(map: &mut [Vec<char>], n: usize, ram_sequence: &[(usize, usize)]) {
-    for index in 0..n {
-        let falling_ram = ram_sequence[index];
+    for falling_ram in &ram_sequence[..n] {
         map[falling_ram.1][falling_ram.0] = '#';
     }
 }
@@ -98,6 +135,30 @@ fn make_moves(
     }
 }
 
+fn find_shortest_path_bfs(map: &[Vec<char>]) -> usize {
+    let mut graph = vec![vec![100_000; map[0].len()]; map.len()];
+    let mut queue = VecDeque::from([(0, 0)]);
+    graph[0][0] = 0;
+
+    while let Some((y, x)) = queue.pop_front() {
+        for (delta_y, delta_x) in [(0, 1), (0, -1), (1, 0), (-1, 0)] {
+            let new_y = y as i32 + delta_y;
+            let new_x = x as i32 + delta_x;
+            if new_y < 0 || new_x < 0 || new_y >= map.len() as i32 || new_x >= map[0].len() as i32 {
+                continue;
+            }
+
+            let (new_y, new_x) = (new_y as usize, new_x as usize);
+            if map[new_y][new_x] != '#' && graph[new_y][new_x] == 100_000 {
+                graph[new_y][new_x] = graph[y][x] + 1;
+                queue.push_back((new_y, new_x));
+            }
+        }
+    }
+
+    graph[graph.len() - 1][graph[0].len() - 1]
+}
+
 #[test]
 fn small_input() {
     let input = "5,4
@@ -130,8 +191,13 @@ fn small_input() {
     make_n_ram_fall(&mut map, 12, &ram_sequence);
 
     assert_eq!(22, find_shortest_path(&map));
+    assert_eq!(22, find_shortest_path_bfs(&map));
+    assert_eq!(
+        (6, 1),
+        find_first_blocker(&mut vec![vec!['.'; 7]; 7], &ram_sequence, find_shortest_path)
+    );
     assert_eq!(
         (6, 1),
-        find_first_blocker(&mut vec![vec!['.'; 7]; 7], &ram_sequence)
+        find_first_blocker(&mut vec![vec!['.'; 7]; 7], &ram_sequence, find_shortest_path_bfs)
     );
 }
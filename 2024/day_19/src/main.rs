@@ -1,15 +1,19 @@
+use aoc::{Answer, Solution};
 use std::collections::HashSet;
-use std::fs;
-use std::time::Instant;
 
-fn main() {
-    let input = fs::read_to_string("input.txt").expect("Could not read file");
+struct Day19;
 
-    let start = Instant::now();
-    println!("Part One: {} - {:?}", part_one(&input), start.elapsed());
+impl Solution for Day19 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 19;
 
-    // let start = Instant::now();
-    // println!("Part Two: {} - {:?}", part_two(&input), start.elapsed());
+    fn part_one(input: &str) -> impl Into<Answer> {
+        part_one(input)
+    }
+}
+
+fn main() {
+    aoc::run::<Day19>();
 }
 
 fn part_one(input: &str) -> usize {
@@ -0,0 +1,382 @@
+use aoc::{Answer, Command, Implementation, Solution};
+use std::ops::RangeInclusive;
+use crate::Direction::{Ascending, Descending};
+use crate::State::{Safe, Unsafe};
+
+#[derive(PartialEq, Debug)]
+enum State {
+    Safe,
+    Unsafe(Violation),
+}
+
+/// The first pair of levels that breaks the rules: `index` and `index + 1`.
+#[derive(PartialEq, Debug)]
+struct Violation {
+    index: usize,
+    kind: ViolationKind,
+    /// The observed change from the first level of the pair to the second.
+    delta: i32,
+}
+
+#[derive(PartialEq, Debug)]
+enum ViolationKind {
+    DeltaOutOfRange,
+    DirectionFlip,
+    DriftExceeded,
+}
+
+#[derive(PartialEq, Debug)]
+struct Dampened {
+    state: State,
+    /// Indices of the fewest levels whose removal makes the report safe, empty when it stays unsafe.
+    removed: Vec<usize>,
+}
+
+#[derive(PartialEq, Clone, Copy)]
+enum Direction {
+    Ascending,
+    Descending
+}
+
+#[derive(Clone)]
+struct SafetyRules {
+    /// Allowed size of a change between neighbouring levels.
+    deltas: RangeInclusive<i32>,
+    /// Every change must go the same way as the first; when false the levels may change direction.
+    strict_direction: bool,
+    /// Neighbouring levels may be equal, regardless of `deltas`.
+    allow_plateaus: bool,
+    /// How far any level may stray from the first one.
+    max_drift: Option<i32>,
+}
+
+struct Day2;
+
+impl Solution for Day2 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 2;
+
+    fn part_one(input: &str) -> impl Into<Answer> {
+        part_one(input)
+    }
+
+    fn part_two(input: &str) -> Option<impl Into<Answer>> {
+        Some(part_two(input))
+    }
+
+    fn alternatives() -> Vec<Implementation> {
+        vec![Implementation { name: "brute_force", part: 2, solve: |input| part_two_brute_force(input).into() }]
+    }
+
+    fn commands() -> Vec<Command> {
+        vec![
+            Command {
+                name: "screen",
+                about: "Count safe reports under other rules [--deltas MIN..=MAX] [--non-strict] [--plateaus] [--max-drift N] [--tolerance K]",
+                run: screen,
+            },
+            Command {
+                name: "explain",
+                about: "Show why each report is unsafe or which levels were dropped, taking the same options as screen",
+                run: explain,
+            },
+        ]
+    }
+}
+
+impl Default for SafetyRules {
+    fn default() -> Self {
+        SafetyRules { deltas: 1..=3, strict_direction: true, allow_plateaus: false, max_drift: None }
+    }
+}
+
+impl SafetyRules {
+    fn allows(&self, from: i32, to: i32, direction: Option<Direction>) -> bool {
+        self.check(from, to, direction).is_ok()
+    }
+
+    fn check(&self, from: i32, to: i32, direction: Option<Direction>) -> Result<(), ViolationKind> {
+        if from == to {
+            return if self.allow_plateaus {Ok(())} else {Err(ViolationKind::DeltaOutOfRange)};
+        }
+
+        if !self.deltas.contains(&(from - to).abs()) {
+            Err(ViolationKind::DeltaOutOfRange)
+        } else if self.strict_direction && direction.is_some_and(|direction| direction != direction_of(from, to)) {
+            Err(ViolationKind::DirectionFlip)
+        } else {
+            Ok(())
+        }
+    }
+
+    fn within_drift(&self, first: i32, level: i32) -> bool {
+        self.max_drift.is_none_or(|max_drift| (level - first).abs() <= max_drift)
+    }
+}
+
+fn main() {
+    aoc::run::<Day2>();
+}
+
+fn screen(input: &str, args: &[String]) -> Result<(), String> {
+    let (rules, tolerance) = parse_rules(args, 0)?;
+
+    let safe = parse_input(input).iter()
+        .filter(|report| dampen(report, &rules, tolerance).state == Safe)
+        .count();
+    println!("{} safe reports", safe);
+    Ok(())
+}
+
+fn explain(input: &str, args: &[String]) -> Result<(), String> {
+    let (rules, tolerance) = parse_rules(args, 1)?;
+
+    for (line, report) in parse_input(input).iter().enumerate() {
+        match (to_state(report, &rules), dampen(report, &rules, tolerance)) {
+            (Safe, _) => {}
+            (Unsafe(_), Dampened { state: Safe, removed }) => println!(
+                "Line {} dampened: {} - removed {}",
+                line + 1,
+                render_levels(report, |index| if removed.contains(&index) {("(", ")")} else {("", "")}),
+                removed.iter().map(|&index| format!("{} at index {}", report[index], index)).collect::<Vec<String>>().join(", "),
+            ),
+            (Unsafe(violation), Dampened { state: Unsafe(_), .. }) => println!(
+                "Line {} unsafe: {} - {}",
+                line + 1,
+                render_levels(report, |index| match index {
+                    index if index == violation.index => ("[", ""),
+                    index if index == violation.index + 1 => ("", "]"),
+                    _ => ("", ""),
+                }),
+                violation.describe(),
+            ),
+        }
+    }
+    Ok(())
+}
+
+fn render_levels(report: &[i32], mark: impl Fn(usize) -> (&'static str, &'static str)) -> String {
+    report.iter().enumerate()
+        .map(|(index, level)| {
+            let (open, close) = mark(index);
+            format!("{}{}{}", open, level, close)
+        })
+        .collect::<Vec<String>>()
+        .join(" ")
+}
+
+fn parse_rules(args: &[String], default_tolerance: usize) -> Result<(SafetyRules, usize), String> {
+    let mut rules = SafetyRules::default();
+    let mut tolerance = default_tolerance;
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        let mut value = |name: &str| args.next().ok_or(format!("{} needs a value", name));
+        match arg.as_str() {
+            "--deltas" => {
+                let deltas = value("--deltas")?;
+                let (min, max) = deltas.split_once("..=").ok_or("--deltas looks like 1..=3")?;
+                rules.deltas = parse_number(min)?..=parse_number(max)?;
+            }
+            "--non-strict" => rules.strict_direction = false,
+            "--plateaus" => rules.allow_plateaus = true,
+            "--max-drift" => rules.max_drift = Some(parse_number(value("--max-drift")?)?),
+            "--tolerance" => tolerance = parse_number(value("--tolerance")?)?,
+            other => return Err(format!("Unknown option {}", other)),
+        }
+    }
+
+    Ok((rules, tolerance))
+}
+
+fn parse_number<T: std::str::FromStr>(value: &str) -> Result<T, String> {
+    value.parse().map_err(|_| format!("{} is not a number", value))
+}
+
+fn part_one(input: &str) -> i32{
+    let reports = parse_input(input);
+    reports.into_iter()
+        .map(|report| to_state(&report, &SafetyRules::default()))
+        .filter(|state| *state == Safe)
+        .count() as i32
+}
+
+fn direction_of(from: i32, to: i32) -> Direction {
+    if from > to {Descending} else {Ascending}
+}
+
+fn to_state(report: &[i32], rules: &SafetyRules) -> State{
+    let start_direction = report.windows(2)
+        .find(|pair| pair[0] != pair[1])
+        .map(|pair| direction_of(pair[0], pair[1]));
+
+    for index in 0..report.len().saturating_sub(1) {
+        let (from, to) = (report[index], report[index + 1]);
+        let kind = match rules.check(from, to, start_direction) {
+            Err(kind) => kind,
+            Ok(()) if !rules.within_drift(report[0], to) => ViolationKind::DriftExceeded,
+            Ok(()) => continue,
+        };
+
+        return Unsafe(Violation { index, kind, delta: to - from })
+    }
+
+    Safe
+}
+
+impl Violation {
+    fn describe(&self) -> String {
+        match self.kind {
+            ViolationKind::DeltaOutOfRange => format!("change of {:+} is out of range", self.delta),
+            ViolationKind::DirectionFlip => format!("change of {:+} goes against the report's direction", self.delta),
+            ViolationKind::DriftExceeded => format!("change of {:+} drifts too far from the first level", self.delta),
+        }
+    }
+}
+
+fn part_two(input: &str) -> i32{
+    let reports = parse_input(input);
+    reports.into_iter()
+        .map(|report| dampen(&report, &SafetyRules::default(), 1).state)
+        .filter(|state| *state == Safe)
+        .count() as i32
+}
+
+fn part_two_brute_force(input: &str) -> i32{
+    let reports = parse_input(input);
+    reports.into_iter()
+        .map(|report| to_state_with_dampener(report, &SafetyRules::default()))
+        .filter(|state| *state == Safe)
+        .count() as i32
+}
+
+/// Finds the fewest levels to drop, up to `tolerance`, for each choice of first kept level
+/// and direction. `removals[i]` is the fewest drops that leave level `i` as the last kept level,
+/// which only depends on the `tolerance + 1` levels before it, so this is O(n·k²) rather than O(n^k).
+fn dampen(report: &[i32], rules: &SafetyRules, tolerance: usize) -> Dampened {
+    let directions = if rules.strict_direction {vec!(Some(Ascending), Some(Descending))} else {vec!(None)};
+
+    (0..report.len().min(tolerance + 1))
+        .flat_map(|first| directions.iter().map(move |&direction| (first, direction)))
+        .filter_map(|(first, direction)| dampen_from(report, rules, tolerance, first, direction))
+        .min_by_key(|removed| removed.len())
+        .map_or_else(|| Dampened { state: to_state(report, rules), removed: vec!() }, |removed| Dampened { state: Safe, removed })
+}
+
+fn dampen_from(report: &[i32], rules: &SafetyRules, tolerance: usize, first: usize, direction: Option<Direction>) -> Option<Vec<usize>> {
+    let mut removals = vec![usize::MAX; report.len()];
+    let mut previous_kept = vec![None; report.len()];
+    removals[first] = first;
+
+    for index in first + 1..report.len() {
+        if !rules.within_drift(report[first], report[index]) {
+            continue;
+        }
+
+        for previous in index.saturating_sub(tolerance + 1).max(first)..index {
+            let dropped = removals[previous].saturating_add(index - previous - 1);
+            if dropped < removals[index] && dropped <= tolerance && rules.allows(report[previous], report[index], direction) {
+                removals[index] = dropped;
+                previous_kept[index] = Some(previous);
+            }
+        }
+    }
+
+    let last_kept = (report.len().saturating_sub(tolerance + 1)..report.len())
+        .filter(|&index| removals[index].saturating_add(report.len() - 1 - index) <= tolerance)
+        .min_by_key(|&index| removals[index] + report.len() - 1 - index)?;
+
+    let mut kept = vec![false; report.len()];
+    let mut current = Some(last_kept);
+    while let Some(index) = current {
+        kept[index] = true;
+        current = previous_kept[index];
+    }
+
+    Some((0..report.len()).filter(|&index| !kept[index]).collect())
+}
+
+fn to_state_with_dampener(report: Vec<i32>, rules: &SafetyRules) -> State{
+    match to_state(&report, rules) {
+        Safe => Safe,
+        Unsafe(_) => {
+            for index in 0..report.len() {
+                let mut edited_report = report.clone();
+                edited_report.remove(index);
+                let state_without = to_state(&edited_report, rules);
+                if state_without == Safe {
+                    return Safe;
+                }
+            }
+            
+            to_state(&report, rules)
+        }
+    }
+}
+
+fn parse_input(input: &str) -> Vec<Vec<i32>> {
+    input.lines().map(|line| {
+        line.split_ascii_whitespace()
+            .map(|value| value.parse().unwrap())
+            .collect()
+    })
+        .collect()
+}
+
+#[test]
+fn small_input(){
+    let input = "7 6 4 2 1
+1 2 7 8 9
+9 7 6 2 1
+1 3 2 4 5
+8 6 4 4 1
+1 3 6 7 9";
+
+    assert_eq!(vec!(
+        vec!(7, 6, 4, 2, 1),
+        vec!(1, 2, 7, 8, 9),
+        vec!(9, 7, 6, 2, 1),
+        vec!(1, 3, 2, 4, 5),
+        vec!(8, 6, 4, 4, 1),
+        vec!(1, 3, 6, 7, 9)
+    ), parse_input(input));
+    assert_eq!(2, part_one(input));
+    assert_eq!(4, part_two(input));
+}
+
+#[test]
+fn dampener_finds_the_fewest_levels_to_drop(){
+    assert_eq!(Dampened { state: Safe, removed: vec!() }, dampen(&[7, 6, 4, 2, 1], &SafetyRules::default(), 1));
+    assert_eq!(Dampened { state: Safe, removed: vec!(2) }, dampen(&[1, 3, 2, 4, 5], &SafetyRules::default(), 1));
+    assert_eq!(Dampened { state: Safe, removed: vec!(0) }, dampen(&[9, 1, 2, 3, 4], &SafetyRules::default(), 1));
+    assert_eq!(Dampened { state: Safe, removed: vec!(4) }, dampen(&[1, 2, 3, 4, 9], &SafetyRules::default(), 1));
+    assert_eq!(Dampened { state: Unsafe(Violation { index: 1, kind: ViolationKind::DeltaOutOfRange, delta: 5 }), removed: vec!() }, dampen(&[1, 2, 7, 8, 9], &SafetyRules::default(), 1));
+    assert_eq!(Dampened { state: Safe, removed: vec!(2, 3) }, dampen(&[1, 2, 7, 8, 3, 4], &SafetyRules::default(), 2));
+    assert_eq!(Dampened { state: Unsafe(Violation { index: 1, kind: ViolationKind::DeltaOutOfRange, delta: 5 }), removed: vec!() }, dampen(&[1, 2, 7, 8, 9, 3, 4], &SafetyRules::default(), 2));
+}
+
+#[test]
+fn rules_change_what_counts_as_safe(){
+    let relaxed = SafetyRules { deltas: 1..=5, strict_direction: false, allow_plateaus: true, max_drift: None };
+    let drifting = SafetyRules { max_drift: Some(3), ..SafetyRules::default() };
+
+    assert_eq!(Safe, to_state(&[1, 2, 7, 8, 9], &relaxed));
+    assert_eq!(Safe, to_state(&[1, 3, 2, 2, 5], &relaxed));
+    assert_eq!(Safe, to_state(&[1, 1, 2, 2, 3], &SafetyRules { allow_plateaus: true, ..SafetyRules::default() }));
+    assert_eq!(Safe, to_state(&[1, 2, 3, 4], &drifting));
+
+    assert_eq!(Dampened { state: Safe, removed: vec!(0) }, dampen(&[9, 1, 2, 3, 4], &drifting, 1));
+    assert_eq!(Dampened { state: Safe, removed: vec!(0, 5) }, dampen(&[1, 4, 5, 6, 7, 9], &drifting, 2));
+    assert_eq!(Dampened { state: Safe, removed: vec!(2) }, dampen(&[1, 3, 9, 2, 4], &relaxed, 1));
+}
+
+#[test]
+fn verdicts_pinpoint_the_first_bad_pair(){
+    let plateaus = SafetyRules { allow_plateaus: true, ..SafetyRules::default() };
+    let drifting = SafetyRules { max_drift: Some(3), ..SafetyRules::default() };
+
+    assert_eq!(Unsafe(Violation { index: 1, kind: ViolationKind::DeltaOutOfRange, delta: 5 }), to_state(&[1, 2, 7, 8, 9], &SafetyRules::default()));
+    assert_eq!(Unsafe(Violation { index: 2, kind: ViolationKind::DeltaOutOfRange, delta: 0 }), to_state(&[8, 6, 4, 4, 1], &SafetyRules::default()));
+    assert_eq!(Unsafe(Violation { index: 1, kind: ViolationKind::DirectionFlip, delta: -1 }), to_state(&[1, 3, 2, 2, 5], &plateaus));
+    assert_eq!(Unsafe(Violation { index: 3, kind: ViolationKind::DriftExceeded, delta: 1 }), to_state(&[1, 2, 3, 4, 5], &drifting));
+}
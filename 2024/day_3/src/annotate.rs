@@ -0,0 +1,112 @@
+use crate::interpreter::{Execution, Operation, MULTIPLY};
+use std::ops::Range;
+
+const ENABLED: &str = "\x1b[32m";
+const DISABLED: &str = "\x1b[31m";
+const CONTROL: &str = "\x1b[36m";
+const NEAR_MISS: &str = "\x1b[35m";
+const RESET: &str = "\x1b[0m";
+
+/// Reprints `input` with each instruction coloured by what it did, and anything that starts
+/// like an instruction but isn't one highlighted as a near miss.
+pub fn annotate(input: &str, execution: &Execution, operations: &[Operation]) -> String {
+    let spans = execution.trace.iter().map(|step| step.token.span.clone()).collect::<Vec<Range<usize>>>();
+    let mut highlights = execution.trace.iter()
+        .map(|step| {
+            let colour = match (step.token.instruction.name == MULTIPLY.name, step.enabled) {
+                (true, true) => ENABLED,
+                (true, false) => DISABLED,
+                (false, _) => CONTROL,
+            };
+            (step.token.span.clone(), colour)
+        })
+        .chain(near_misses(input, &spans, operations).into_iter().map(|span| (span, NEAR_MISS)))
+        .collect::<Vec<(Range<usize>, &str)>>();
+    highlights.sort_by_key(|(span, _)| span.start);
+
+    let mut annotated = String::new();
+    let mut written = 0;
+    for (span, colour) in highlights {
+        annotated.push_str(&input[written..span.start]);
+        annotated.push_str(colour);
+        annotated.push_str(&input[span.clone()]);
+        annotated.push_str(RESET);
+        written = span.end;
+    }
+    annotated.push_str(&input[written..]);
+
+    annotated
+}
+
+/// An operation's name outside any instruction, along with any brackets, digits and commas
+/// after it up to the first closing bracket, like `mul[3,7]` or `mul(32,64]`.
+fn near_misses(input: &str, spans: &[Range<usize>], operations: &[Operation]) -> Vec<Range<usize>> {
+    let bytes = input.as_bytes();
+    let inside_instruction = |index: usize| spans.iter().any(|span| span.contains(&index));
+    let mut misses: Vec<Range<usize>> = vec!();
+
+    for start in 0..bytes.len() {
+        if inside_instruction(start) || misses.last().is_some_and(|miss| miss.contains(&start)) {
+            continue;
+        }
+
+        let Some(name) = operations.iter()
+            .map(|operation| operation.name.as_bytes())
+            .filter(|name| bytes[start..].starts_with(name))
+            .max_by_key(|name| name.len())
+        else {
+            continue;
+        };
+
+        let mut end = start + name.len();
+        while end < bytes.len() && !inside_instruction(end) && b"()[]{},0123456789".contains(&bytes[end]) {
+            end += 1;
+            if b")]}".contains(&bytes[end - 1]) {
+                break;
+            }
+        }
+        misses.push(start..end);
+    }
+
+    misses
+}
+
+pub fn print_contributions(execution: &Execution) {
+    for step in &execution.trace {
+        let instruction = &step.token.instruction;
+        let operands = instruction.operands.iter().map(u32::to_string).collect::<Vec<String>>().join(",");
+        println!(
+            "{:>8}  {:<12}  {:<8}  {:+}",
+            step.token.span.start,
+            format!("{}({})", instruction.name, operands),
+            if step.enabled {"enabled"} else {"disabled"},
+            step.contribution,
+        );
+    }
+    println!("Accumulator: {}", execution.accumulator);
+    for (address, value) in &execution.memory {
+        println!("Memory {}: {}", address, value);
+    }
+}
+
+pub fn print_legend() {
+    println!(
+        "{}enabled{}  {}disabled{}  {}control{}  {}near miss{}",
+        ENABLED, RESET, DISABLED, RESET, CONTROL, RESET, NEAR_MISS, RESET
+    );
+}
+
+#[test]
+fn instructions_and_near_misses_are_coloured() {
+    let input = "xmul(2,4)&mul[3,7]!^don't()_mul(5,5)+mul(32,64](mul(11,8)undo()?mul(8,5))";
+    let execution = crate::interpreter::execute(&crate::interpreter::STANDARD, crate::parse_input(input));
+
+    assert_eq!(
+        format!(
+            "x{g}mul(2,4){r}&{m}mul[3,7]{r}!^{c}don't(){r}_{d}mul(5,5){r}+{m}mul(32,64]{r}({d}mul(11,8){r}un{c}do(){r}?{g}mul(8,5){r})",
+            g = ENABLED, d = DISABLED, c = CONTROL, m = NEAR_MISS, r = RESET
+        ),
+        annotate(input, &execution, &crate::interpreter::STANDARD)
+    );
+    assert_eq!(vec!(0..2), near_misses("do_not", &[], &crate::interpreter::STANDARD));
+}
@@ -0,0 +1,96 @@
+use crate::lexer::Token;
+use std::collections::BTreeMap;
+
+pub const MULTIPLY: Operation = Operation { name: "mul", arity: 2, semantics: multiply };
+pub const DO: Operation = Operation { name: "do", arity: 0, semantics: enable };
+pub const DONT: Operation = Operation { name: "don't", arity: 0, semantics: disable };
+pub const STANDARD: [Operation; 3] = [MULTIPLY, DO, DONT];
+
+/// An instruction the lexer should look for, called as `name(operand,...)` with `arity`
+/// operands of one to three digits each.
+pub struct Operation {
+    pub name: &'static str,
+    pub arity: usize,
+    pub semantics: fn(&mut Machine, &[u32]),
+}
+
+pub struct Machine {
+    pub accumulator: i64,
+    pub enabled: bool,
+    pub memory: BTreeMap<u32, i64>,
+}
+
+pub struct Step {
+    pub token: Token,
+    /// Whether the machine was enabled when the instruction ran.
+    pub enabled: bool,
+    pub contribution: i64,
+}
+
+pub struct Execution {
+    pub accumulator: i64,
+    pub memory: BTreeMap<u32, i64>,
+    pub trace: Vec<Step>,
+}
+
+fn multiply(machine: &mut Machine, operands: &[u32]) {
+    if machine.enabled {
+        machine.accumulator += operands[0] as i64 * operands[1] as i64;
+    }
+}
+
+fn enable(machine: &mut Machine, _: &[u32]) {
+    machine.enabled = true;
+}
+
+fn disable(machine: &mut Machine, _: &[u32]) {
+    machine.enabled = false;
+}
+
+/// Runs each token with the first of `operations` matching its name and arity.
+pub fn execute(operations: &[Operation], tokens: impl IntoIterator<Item = Token>) -> Execution {
+    let mut machine = Machine { accumulator: 0, enabled: true, memory: BTreeMap::new() };
+    let mut trace = vec!();
+
+    for token in tokens {
+        let Some(operation) = operations.iter()
+            .find(|operation| operation.name == token.instruction.name && operation.arity == token.instruction.operands.len())
+        else {
+            continue;
+        };
+
+        let (enabled, before) = (machine.enabled, machine.accumulator);
+        (operation.semantics)(&mut machine, &token.instruction.operands);
+        trace.push(Step { token, enabled, contribution: machine.accumulator - before });
+    }
+
+    Execution { accumulator: machine.accumulator, memory: machine.memory, trace }
+}
+
+#[test]
+fn new_operations_plug_into_the_lexer_and_interpreter() {
+    let operations = [
+        MULTIPLY,
+        Operation { name: "add", arity: 1, semantics: |machine, operands| machine.accumulator += operands[0] as i64 },
+        Operation { name: "sub", arity: 1, semantics: |machine, operands| machine.accumulator -= operands[0] as i64 },
+        Operation { name: "store", arity: 1, semantics: |machine, operands| {
+            machine.memory.insert(operands[0], machine.accumulator);
+        }},
+        Operation { name: "load", arity: 1, semantics: |machine, operands| {
+            machine.accumulator = machine.memory.get(&operands[0]).copied().unwrap_or_default();
+        }},
+        Operation { name: "unless", arity: 1, semantics: |machine, operands| {
+            machine.enabled = machine.accumulator != operands[0] as i64;
+        }},
+    ];
+    let input = "add(10)store(1)sub(3)mul(2,3)unless(13)mul(5,5)sub(13)unless(5)mul(1,1)load(1)";
+
+    let execution = execute(&operations, crate::lexer::Lexer::new(input.as_bytes(), &operations).map_while(Result::ok));
+
+    assert_eq!(10, execution.accumulator);
+    assert_eq!(
+        vec!(("add", 10), ("store", 0), ("sub", -3), ("mul", 6), ("unless", 0), ("mul", 0), ("sub", -13), ("unless", 0), ("mul", 1), ("load", 9)),
+        execution.trace.iter().map(|step| (step.token.instruction.name, step.contribution)).collect::<Vec<(&str, i64)>>()
+    );
+    assert!(!execution.trace[5].enabled);
+}
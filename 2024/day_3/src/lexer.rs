@@ -0,0 +1,258 @@
+use crate::interpreter::Operation;
+use std::io::{self, ErrorKind, Read};
+use std::ops::Range;
+
+const CHUNK_SIZE: usize = 8 * 1024;
+const MAX_DIGITS: u8 = 3;
+
+/// A call to a registered operation, such as `mul(2,4)`.
+#[derive(PartialEq, Debug, Clone)]
+pub struct Instruction {
+    pub name: &'static str,
+    pub operands: Vec<u32>,
+}
+
+#[derive(PartialEq, Debug)]
+pub struct Token {
+    pub instruction: Instruction,
+    /// Byte offsets of the whole instruction in the input.
+    pub span: Range<usize>,
+}
+
+enum Match {
+    Complete(Instruction),
+    Partial,
+    Invalid,
+}
+
+/// Finds calls to `operations` in corrupted memory one byte at a time, reading `reader` in chunks.
+/// A call can only end at its `)`, so a candidate that goes wrong is retried from its next byte
+/// and only ever holds the bytes of one instruction.
+pub struct Lexer<'a, R> {
+    reader: R,
+    operations: &'a [Operation],
+    chunk: Vec<u8>,
+    position: usize,
+    offset: usize,
+    candidate: Vec<u8>,
+    start: usize,
+    finished: bool,
+}
+
+impl<'a, R: Read> Lexer<'a, R> {
+    pub fn new(reader: R, operations: &'a [Operation]) -> Lexer<'a, R> {
+        Lexer {
+            reader,
+            operations,
+            chunk: Vec::with_capacity(CHUNK_SIZE),
+            position: 0,
+            offset: 0,
+            candidate: vec!(),
+            start: 0,
+            finished: false,
+        }
+    }
+
+    fn next_byte(&mut self) -> io::Result<Option<(usize, u8)>> {
+        if self.position == self.chunk.len() {
+            self.chunk.resize(CHUNK_SIZE, 0);
+            let read = loop {
+                match self.reader.read(&mut self.chunk) {
+                    Err(error) if error.kind() == ErrorKind::Interrupted => continue,
+                    read => break read,
+                }
+            };
+            let read = read.inspect_err(|_| self.chunk.clear())?;
+            self.offset += self.position;
+            self.chunk.truncate(read);
+            self.position = 0;
+            if read == 0 {
+                return Ok(None);
+            }
+        }
+
+        self.position += 1;
+        Ok(Some((self.offset + self.position - 1, self.chunk[self.position - 1])))
+    }
+
+    fn advance(&mut self, index: usize, byte: u8) -> Option<Token> {
+        if self.candidate.is_empty() {
+            self.start = index;
+        }
+        self.candidate.push(byte);
+
+        while !self.candidate.is_empty() {
+            let mut partial = false;
+            for operation in self.operations {
+                match match_operation(operation, &self.candidate) {
+                    Match::Complete(instruction) => {
+                        self.candidate.clear();
+                        return Some(Token { instruction, span: self.start..index + 1 });
+                    }
+                    Match::Partial => partial = true,
+                    Match::Invalid => {}
+                }
+            }
+            if partial {
+                break;
+            }
+
+            self.candidate.remove(0);
+            self.start += 1;
+        }
+
+        None
+    }
+}
+
+fn match_operation(operation: &Operation, bytes: &[u8]) -> Match {
+    let name = operation.name.as_bytes();
+    if bytes.len() <= name.len() {
+        return if name.starts_with(bytes) {Match::Partial} else {Match::Invalid};
+    }
+    if !bytes.starts_with(name) || bytes[name.len()] != b'(' {
+        return Match::Invalid;
+    }
+
+    let mut operands = vec!();
+    let (mut value, mut digits) = (0, 0);
+    for &byte in &bytes[name.len() + 1..] {
+        match byte {
+            b'0'..=b'9' if digits < MAX_DIGITS && operands.len() < operation.arity => {
+                value = value * 10 + (byte - b'0') as u32;
+                digits += 1;
+            }
+            b',' if digits > 0 && operands.len() + 1 < operation.arity => {
+                operands.push(value);
+                (value, digits) = (0, 0);
+            }
+            b')' if operation.arity == 0 => return Match::Complete(Instruction { name: operation.name, operands }),
+            b')' if digits > 0 && operands.len() + 1 == operation.arity => {
+                operands.push(value);
+                return Match::Complete(Instruction { name: operation.name, operands });
+            }
+            _ => return Match::Invalid,
+        }
+    }
+
+    Match::Partial
+}
+
+impl<R: Read> Iterator for Lexer<'_, R> {
+    type Item = io::Result<Token>;
+
+    fn next(&mut self) -> Option<io::Result<Token>> {
+        while !self.finished {
+            match self.next_byte() {
+                Ok(Some((index, byte))) => {
+                    if let Some(token) = self.advance(index, byte) {
+                        return Some(Ok(token));
+                    }
+                }
+                Ok(None) => self.finished = true,
+                Err(error) => {
+                    self.finished = true;
+                    return Some(Err(error));
+                }
+            }
+        }
+
+        None
+    }
+}
+
+#[cfg(test)]
+use crate::interpreter::STANDARD;
+
+#[cfg(test)]
+fn call(name: &'static str, operands: &[u32]) -> Instruction {
+    Instruction { name, operands: operands.to_vec() }
+}
+
+/// Hands out a few bytes at a time so instructions straddle chunk boundaries.
+#[cfg(test)]
+struct Trickle<'a> {
+    bytes: &'a [u8],
+    sizes: std::iter::Cycle<std::slice::Iter<'a, usize>>,
+}
+
+#[cfg(test)]
+impl Read for Trickle<'_> {
+    fn read(&mut self, buffer: &mut [u8]) -> io::Result<usize> {
+        let size = (*self.sizes.next().unwrap()).min(buffer.len()).min(self.bytes.len());
+        buffer[..size].copy_from_slice(&self.bytes[..size]);
+        self.bytes = &self.bytes[size..];
+        Ok(size)
+    }
+}
+
+#[test]
+fn tokens_carry_their_spans() {
+    let input = b"xmul(2,4)&mul[3,7]!^don't()_mul(5,5)+mul(32,64](mul(11,8)undo()?mul(8,5))mul(1,2,3)mul(1234,5)";
+    let tokens = Lexer::new(Trickle { bytes: input, sizes: [1, 2, 3].iter().cycle() }, &STANDARD)
+        .collect::<io::Result<Vec<Token>>>()
+        .unwrap();
+
+    assert_eq!(
+        vec!(
+            Token { instruction: call("mul", &[2, 4]), span: 1..9 },
+            Token { instruction: call("don't", &[]), span: 20..27 },
+            Token { instruction: call("mul", &[5, 5]), span: 28..36 },
+            Token { instruction: call("mul", &[11, 8]), span: 48..57 },
+            Token { instruction: call("do", &[]), span: 59..63 },
+            Token { instruction: call("mul", &[8, 5]), span: 64..72 },
+        ),
+        tokens
+    );
+}
+
+#[test]
+fn fuzzed_bytes_never_panic_and_match_the_grammar() {
+    let grammar = regex::bytes::Regex::new(r"mul\(([0-9]{1,3}),([0-9]{1,3})\)|do\(\)|don't\(\)").unwrap();
+    let alphabet = b"mul(),don't0123456789 \xff\x00";
+    let mut lcg = aoc::Lcg::new(3);
+    let mut random = || (lcg.next_u64() >> 33) as usize;
+
+    for _ in 0..2000 {
+        let length = random() % 200;
+        let input = (0..length)
+            .map(|_| if random() % 8 == 0 {random() as u8} else {alphabet[random() % alphabet.len()]})
+            .collect::<Vec<u8>>();
+        let sizes = [1 + random() % 16, 1 + random() % 16];
+
+        let tokens = Lexer::new(Trickle { bytes: &input, sizes: sizes.iter().cycle() }, &STANDARD)
+            .collect::<io::Result<Vec<Token>>>()
+            .unwrap();
+        let expected = grammar.captures_iter(&input)
+            .map(|captures| {
+                let whole = captures.get(0).unwrap();
+                let instruction = match (captures.get(1), captures.get(2)) {
+                    (Some(x), Some(y)) => call("mul", &[
+                        std::str::from_utf8(x.as_bytes()).unwrap().parse().unwrap(),
+                        std::str::from_utf8(y.as_bytes()).unwrap().parse().unwrap(),
+                    ]),
+                    _ if whole.as_bytes() == b"do()" => call("do", &[]),
+                    _ => call("don't", &[]),
+                };
+                Token { instruction, span: whole.range() }
+            })
+            .collect::<Vec<Token>>();
+
+        assert_eq!(expected, tokens, "{:?}", String::from_utf8_lossy(&input));
+    }
+}
+
+#[test]
+fn overlapping_names_are_retried_from_the_next_byte() {
+    let noop = |_: &mut crate::interpreter::Machine, _: &[u32]| {};
+    let operations = [
+        Operation { name: "add", arity: 1, semantics: noop },
+        Operation { name: "do", arity: 0, semantics: noop },
+        Operation { name: "dodo", arity: 2, semantics: noop },
+    ];
+    let names = Lexer::new(&b"adddo()add(1,2)dodo(1)dodo(4,5)add(7)"[..], &operations)
+        .map(|token| token.unwrap().instruction)
+        .collect::<Vec<Instruction>>();
+
+    assert_eq!(vec!(call("do", &[]), call("dodo", &[4, 5]), call("add", &[7])), names);
+}
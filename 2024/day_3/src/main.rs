@@ -0,0 +1,99 @@
+mod annotate;
+mod interpreter;
+mod lexer;
+
+use aoc::{Answer, Command, Solution};
+use crate::interpreter::{execute, MULTIPLY, STANDARD};
+use crate::lexer::{Lexer, Token};
+#[cfg(test)]
+use crate::lexer::Instruction;
+
+struct Day3;
+
+impl Solution for Day3 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 3;
+
+    fn part_one(input: &str) -> impl Into<Answer> {
+        part_one(input)
+    }
+
+    fn part_two(input: &str) -> Option<impl Into<Answer>> {
+        Some(part_two(input))
+    }
+
+    fn commands() -> Vec<Command> {
+        vec![
+            Command {
+                name: "trace",
+                about: "Every instruction run, whether it was enabled and what it added",
+                run: |input, _| {
+                    annotate::print_contributions(&execute(&STANDARD, parse_input(input)));
+                    Ok(())
+                },
+            },
+            Command {
+                name: "annotate",
+                about: "The input with enabled, disabled, control and near-miss instructions coloured, then each contribution",
+                run: |input, _| {
+                    let execution = execute(&STANDARD, parse_input(input));
+                    annotate::print_legend();
+                    println!("{}", annotate::annotate(input, &execution, &STANDARD));
+                    println!();
+                    annotate::print_contributions(&execution);
+                    Ok(())
+                },
+            },
+        ]
+    }
+}
+
+fn main() {
+    aoc::run::<Day3>();
+}
+
+fn part_one(input: &str) -> i64 {
+    execute(&[MULTIPLY], parse_input(input)).accumulator
+}
+
+fn part_two(input: &str) -> i64 {
+    execute(&STANDARD, parse_input(input)).accumulator
+}
+
+fn parse_input(input: &str) -> Vec<Token> {
+    Lexer::new(input.as_bytes(), &STANDARD)
+        .map_while(Result::ok)
+        .collect()
+}
+
+#[test]
+fn small_input() {
+    let input = "xmul(2,4)%&mul[3,7]!@^do_not_mul(5,5)+mul(32,64]then(mul(11,8)mul(8,5))";
+    assert_eq!(
+        vec!(
+            Instruction { name: "mul", operands: vec!(2, 4) },
+            Instruction { name: "mul", operands: vec!(5, 5) },
+            Instruction { name: "mul", operands: vec!(11, 8) },
+            Instruction { name: "mul", operands: vec!(8, 5) }
+        ),
+        parse_input(input).into_iter().map(|token| token.instruction).collect::<Vec<Instruction>>()
+    );
+    assert_eq!(161, part_one(input));
+}
+
+#[test]
+fn with_controls() {
+    let input = "xmul(2,4)&mul[3,7]!^don't()_mul(5,5)+mul(32,64](mul(11,8)undo()?mul(8,5))";
+    assert_eq!(
+        vec!(
+            Instruction { name: "mul", operands: vec!(2, 4) },
+            Instruction { name: "don't", operands: vec!() },
+            Instruction { name: "mul", operands: vec!(5, 5) },
+            Instruction { name: "mul", operands: vec!(11, 8) },
+            Instruction { name: "do", operands: vec!() },
+            Instruction { name: "mul", operands: vec!(8, 5) }
+        ),
+        parse_input(input).into_iter().map(|token| token.instruction).collect::<Vec<Instruction>>()
+    );
+    assert_eq!(48, part_two(input));
+}
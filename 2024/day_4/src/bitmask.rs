@@ -0,0 +1,132 @@
+use crate::search::Direction;
+use aoc::Lcg;
+use std::collections::HashMap;
+
+const BITS: isize = u64::BITS as isize;
+
+/// One bit per column for each letter and row, so a whole row of 64 columns at a time
+/// can be checked for a letter with a shift and an AND.
+pub struct LetterMasks {
+    rows: usize,
+    words_per_row: usize,
+    /// Masks for ASCII letters by code, empty when the letter is missing.
+    ascii: Vec<Vec<u64>>,
+    other: HashMap<char, Vec<u64>>,
+}
+
+impl LetterMasks {
+    pub fn new(input: &str) -> LetterMasks {
+        let lines = input.lines().collect::<Vec<&str>>();
+        let width = lines.iter().map(|line| line.chars().count()).max().unwrap_or_default();
+        let words_per_row = width.div_ceil(BITS as usize);
+        let size = lines.len() * words_per_row;
+        let mut ascii = vec![vec!(); 128];
+        let mut other = HashMap::new();
+
+        for (row, line) in lines.iter().enumerate() {
+            for (column, letter) in line.chars().enumerate() {
+                let mask = if letter.is_ascii() {&mut ascii[letter as usize]} else {other.entry(letter).or_default()};
+                if mask.is_empty() {
+                    mask.resize(size, 0);
+                }
+                mask[row * words_per_row + column / BITS as usize] |= 1 << (column % BITS as usize);
+            }
+        }
+
+        LetterMasks { rows: lines.len(), words_per_row, ascii, other }
+    }
+
+    fn mask(&self, letter: char) -> &[u64] {
+        if letter.is_ascii() {
+            &self.ascii[letter as usize]
+        } else {
+            self.other.get(&letter).map_or(&[], Vec::as_slice)
+        }
+    }
+
+    /// Word `index` of `mask` for `row`, moved so that bit `c` holds column `c + shift`.
+    fn word(&self, mask: &[u64], row: isize, index: usize, shift: isize) -> u64 {
+        if mask.is_empty() || row < 0 || row >= self.rows as isize {
+            return 0;
+        }
+        let row = &mask[row as usize * self.words_per_row..(row as usize + 1) * self.words_per_row];
+        let word_at = |word: isize| if word >= 0 {row.get(word as usize).copied().unwrap_or(0)} else {0};
+
+        let start = index as isize * BITS + shift;
+        let (word, bit) = (start.div_euclid(BITS), start.rem_euclid(BITS));
+        let high = if bit == 0 {0} else {word_at(word + 1) << (BITS - bit)};
+
+        (word_at(word) >> bit) | high
+    }
+
+    /// How many times `word` can be read along `directions`, 64 starting columns at a time.
+    pub fn count_word(&self, word: &str, directions: &[Direction]) -> usize {
+        let masks = word.chars().map(|letter| self.mask(letter)).collect::<Vec<&[u64]>>();
+        let mut total = 0;
+
+        for direction in directions {
+            let (row_step, column_step) = direction.vector();
+            for row in 0..self.rows as isize {
+                for index in 0..self.words_per_row {
+                    let mut starts = !0;
+                    for (offset, mask) in masks.iter().enumerate() {
+                        let offset = offset as isize;
+                        starts &= self.word(mask, row + offset * row_step, index, offset * column_step);
+                        if starts == 0 {
+                            break;
+                        }
+                    }
+                    total += starts.count_ones() as usize;
+                }
+            }
+        }
+
+        total
+    }
+
+    /// Centres of two diagonal `MAS`es crossing on their `A`, read either way.
+    pub fn count_x_mas(&self) -> usize {
+        let (m, a, s) = (self.mask('M'), self.mask('A'), self.mask('S'));
+        let mut total = 0;
+
+        for row in 1..self.rows as isize - 1 {
+            for index in 0..self.words_per_row {
+                let corner = |mask, row_offset, column_offset| self.word(mask, row + row_offset, index, column_offset);
+                let falling = (corner(m, -1, -1) & corner(s, 1, 1)) | (corner(s, -1, -1) & corner(m, 1, 1));
+                let rising = (corner(m, -1, 1) & corner(s, 1, -1)) | (corner(s, -1, 1) & corner(m, 1, -1));
+
+                total += (corner(a, 0, 0) & falling & rising).count_ones() as usize;
+            }
+        }
+
+        total
+    }
+}
+
+/// A square grid of random `XMAS` letters for benchmarking.
+pub fn generate_grid(size: usize) -> String {
+    let mut random = Lcg::new(4);
+    let mut grid = String::with_capacity(size * (size + 1));
+
+    for _ in 0..size {
+        for _ in 0..size {
+            grid.push(['X', 'M', 'A', 'S'][(random.next_u64() >> 62) as usize]);
+        }
+        grid.push('\n');
+    }
+
+    grid
+}
+
+#[test]
+fn masks_agree_across_word_boundaries() {
+    let grid = generate_grid(150);
+    let masks = LetterMasks::new(&grid);
+
+    assert_eq!(crate::part_one(&grid), masks.count_word(crate::XMAS, &Direction::ALL));
+    assert_eq!(crate::part_two(&grid), masks.count_x_mas());
+    assert_eq!(
+        crate::search::find_words(&crate::parse_input(&grid), &["SAMXS"], &crate::search::DirectionSet::diagonal()).len(),
+        masks.count_word("SAMXS", &crate::search::DirectionSet::diagonal().directions)
+    );
+}
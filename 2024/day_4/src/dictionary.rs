@@ -0,0 +1,137 @@
+use crate::search::{Direction, Match};
+use std::collections::{HashMap, VecDeque};
+
+#[derive(Default)]
+struct Node {
+    next: HashMap<char, usize>,
+    /// The longest proper suffix of this node's prefix that is also a prefix in the trie.
+    fail: usize,
+    /// Words ending here, including those ending at any node along the fail links.
+    words: Vec<usize>,
+}
+
+/// An Aho–Corasick automaton over many words, so every line of the grid is read once
+/// whatever the number of words.
+pub struct Dictionary {
+    nodes: Vec<Node>,
+    lengths: Vec<usize>,
+}
+
+impl Dictionary {
+    pub fn new(words: &[&str]) -> Dictionary {
+        let mut nodes = vec!(Node::default());
+
+        for (index, word) in words.iter().enumerate() {
+            let mut node = 0;
+            for letter in word.chars() {
+                node = match nodes[node].next.get(&letter) {
+                    Some(&next) => next,
+                    None => {
+                        nodes.push(Node::default());
+                        let next = nodes.len() - 1;
+                        nodes[node].next.insert(letter, next);
+                        next
+                    }
+                };
+            }
+            if node != 0 {
+                nodes[node].words.push(index);
+            }
+        }
+
+        let mut queue = nodes[0].next.values().copied().collect::<VecDeque<usize>>();
+        while let Some(node) = queue.pop_front() {
+            let children = nodes[node].next.iter().map(|(&letter, &child)| (letter, child)).collect::<Vec<(char, usize)>>();
+            for (letter, child) in children {
+                let mut fallback = nodes[node].fail;
+                let fail = loop {
+                    if let Some(&fail) = nodes[fallback].next.get(&letter) {
+                        break fail;
+                    }
+                    if fallback == 0 {
+                        break 0;
+                    }
+                    fallback = nodes[fallback].fail;
+                };
+                nodes[child].fail = fail;
+                let inherited = nodes[fail].words.clone();
+                nodes[child].words.extend(inherited);
+                queue.push_back(child);
+            }
+        }
+
+        Dictionary { nodes, lengths: words.iter().map(|word| word.chars().count()).collect() }
+    }
+
+    fn step(&self, mut node: usize, letter: char) -> usize {
+        loop {
+            if let Some(&next) = self.nodes[node].next.get(&letter) {
+                return next;
+            }
+            if node == 0 {
+                return 0;
+            }
+            node = self.nodes[node].fail;
+        }
+    }
+
+    /// Every occurrence of every word along each row, column and diagonal, read both ways.
+    pub fn find(&self, grid: &[Vec<char>]) -> Vec<Match> {
+        let mut matches = vec!();
+
+        for direction in Direction::ALL {
+            for line in lines(grid, direction) {
+                let mut node = 0;
+                for (index, &(row, column)) in line.iter().enumerate() {
+                    node = self.step(node, grid[row][column]);
+                    for &word in &self.nodes[node].words {
+                        matches.push(Match { word, start: line[index + 1 - self.lengths[word]], direction });
+                    }
+                }
+            }
+        }
+
+        matches
+    }
+}
+
+/// The cells of each line through the grid in `direction`, each starting at the grid's edge.
+fn lines(grid: &[Vec<char>], direction: Direction) -> Vec<Vec<(usize, usize)>> {
+    let (row_step, column_step) = direction.vector();
+    let in_grid = |row: isize, column: isize| {
+        row >= 0 && column >= 0 && grid.get(row as usize).is_some_and(|letters| (column as usize) < letters.len())
+    };
+    let mut lines = vec!();
+
+    for (row, letters) in grid.iter().enumerate() {
+        for column in 0..letters.len() {
+            let (mut row, mut column) = (row as isize, column as isize);
+            if in_grid(row - row_step, column - column_step) {
+                continue;
+            }
+
+            let mut line = vec!();
+            while in_grid(row, column) {
+                line.push((row as usize, column as usize));
+                (row, column) = (row + row_step, column + column_step);
+            }
+            lines.push(line);
+        }
+    }
+
+    lines
+}
+
+#[test]
+fn one_pass_finds_the_same_matches_as_searching_each_word() {
+    let grid = crate::parse_input(crate::EXAMPLE);
+    let words = ["XMAS", "MAS", "AS", "S", "SAMX", "MMM", "XMASAMX", "Q"];
+
+    let mut found = Dictionary::new(&words).find(&grid);
+    let mut expected = crate::search::find_words(&grid, &words, &crate::search::DirectionSet::all());
+    found.sort();
+    expected.sort();
+
+    assert_eq!(expected, found);
+    assert_eq!(18, found.iter().filter(|found| found.word == 0).count());
+}
@@ -0,0 +1,313 @@
+mod bitmask;
+mod dictionary;
+mod search;
+mod template;
+
+use aoc::{Answer, Command, Implementation, Solution};
+use crate::bitmask::{generate_grid, LetterMasks};
+use crate::dictionary::Dictionary;
+use crate::search::{find_words, Direction, DirectionSet};
+use crate::template::{find_template, Symmetry, Template};
+use std::time::Instant;
+
+const XMAS: &str = "XMAS";
+const X_MAS: &str = "M.S/.A./M.S";
+#[cfg(test)]
+const EXAMPLE: &str = "MMMSXXMASM
+MSAMXMSMSA
+AMXSXMAAMM
+MSAMASMSMX
+XMASAMXAMM
+XXAMMXXAMA
+SMSMSASXSS
+SAXAMASAAA
+MAMMMXMMMM
+MXMXAXMASX";
+const XMAS_SEARCH_SEQUENCE: [char; 4] = ['X', 'M', 'A', 'S'];
+const MAS_OPTIONS: [[char; 3]; 2] = [['M','A','S'],['S','A','M']];
+
+struct Day4;
+
+impl Solution for Day4 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 4;
+
+    fn part_one(input: &str) -> impl Into<Answer> {
+        part_one(input)
+    }
+
+    fn part_two(input: &str) -> Option<impl Into<Answer>> {
+        Some(part_two(input))
+    }
+
+    fn alternatives() -> Vec<Implementation> {
+        vec![
+            Implementation { name: "recursive", part: 1, solve: |input| part_one_recursive(input).into() },
+            Implementation {
+                name: "dictionary",
+                part: 1,
+                solve: |input| Dictionary::new(&[XMAS]).find(&parse_input(input)).len().into(),
+            },
+            Implementation {
+                name: "bitmask",
+                part: 1,
+                solve: |input| LetterMasks::new(input).count_word(XMAS, &Direction::ALL).into(),
+            },
+            Implementation { name: "recursive", part: 2, solve: |input| part_two_recursive(input).into() },
+            Implementation { name: "bitmask", part: 2, solve: |input| LetterMasks::new(input).count_x_mas().into() },
+        ]
+    }
+
+    fn commands() -> Vec<Command> {
+        vec![
+            Command {
+                name: "find",
+                about: "Every match of the given words [--directions orthogonal|diagonal|all] [--wrap] WORD...",
+                run: find,
+            },
+            Command {
+                name: "dictionary",
+                about: "Counts for every word in a file, one per line, found in a single pass [--positions] FILE",
+                run: dictionary,
+            },
+            Command {
+                name: "bench",
+                about: "Time the bitmask search against the default and recursive ones on a generated grid [--size N]",
+                run: |_, args| bench(args),
+            },
+            Command {
+                name: "stencil",
+                about: "Every placement of a template like M.S/.A./M.S, with . as a wildcard [--rotations] [--reflections]",
+                run: stencil,
+            },
+        ]
+    }
+}
+
+fn main() {
+    aoc::run::<Day4>();
+}
+
+fn part_one(input: &str) -> usize {
+    find_words(&parse_input(input), &[XMAS], &DirectionSet::all()).len()
+}
+
+fn part_one_recursive(input: &str) -> usize {
+    count_instances(&parse_input(input))
+}
+
+fn count_instances(grid: &[Vec<char>]) -> usize {
+    let mut total: usize = 0;
+
+    for column_index in 0..grid.len() {
+        for row_index in 0..grid[column_index].len() {
+            if grid[column_index][row_index] == XMAS_SEARCH_SEQUENCE[0] {
+                total += count_instances_at_position(grid, (column_index, row_index));
+            }
+        }
+    }
+
+    total
+}
+
+fn count_instances_at_position(grid: &[Vec<char>], location: (usize, usize)) -> usize {
+    [
+        (0, 1),
+        (1, 1),
+        (1, 0),
+        (1, -1),
+        (-1, 0),
+        (-1, -1),
+        (0, -1),
+        (-1, 1),
+    ]
+        .map(|vector| check_string_in_direction(grid, (location.0 as i32, location.1 as i32), vector, 1))
+        .iter()
+        .filter(|result| **result)
+        .count()
+}
+
+fn check_string_in_direction(grid: &[Vec<char>], location: (i32, i32), vector: (i32, i32), index: usize) -> bool {
+    if index > XMAS_SEARCH_SEQUENCE.len() - 1 {
+        return true
+    }
+
+    if !new_location_is_in_grid(grid, location, vector) {
+        return false
+    }
+    let location_to_check = ((location.0 + vector.0) as usize, (location.1 + vector.1) as usize);
+
+    if grid[location_to_check.0][location_to_check.1] != XMAS_SEARCH_SEQUENCE[index] {
+       false
+    } else {
+       check_string_in_direction(grid, (location_to_check.0 as i32, location_to_check.1 as i32), vector, index + 1)
+    }
+}
+
+fn new_location_is_in_grid(grid: &[Vec<char>], location: (i32, i32), vector: (i32, i32)) -> bool {
+    location.0 + vector.0 >= 0 &&
+        location.0 + vector.0 < grid.len() as i32 &&
+        location.1 + vector.1 >= 0 &&
+        location.1 + vector.1 < grid[0].len() as i32
+}
+
+fn find(input: &str, args: &[String]) -> Result<(), String> {
+    let mut directions = DirectionSet::all();
+    let mut wraparound = false;
+    let mut words = vec!();
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--directions" => {
+                directions = match args.next().map(String::as_str) {
+                    Some("orthogonal") => DirectionSet::orthogonal(),
+                    Some("diagonal") => DirectionSet::diagonal(),
+                    Some("all") => DirectionSet::all(),
+                    _ => return Err(String::from("--directions is one of orthogonal, diagonal or all")),
+                }
+            }
+            "--wrap" => wraparound = true,
+            word => words.push(word),
+        }
+    }
+    if wraparound {
+        directions = directions.wrapping();
+    }
+    if words.is_empty() {
+        return Err(String::from("Give at least one word to find"));
+    }
+
+    let matches = find_words(&parse_input(input), &words, &directions);
+    for found in &matches {
+        println!("{} at row {}, column {} going {:?}", words[found.word], found.start.0, found.start.1, found.direction);
+    }
+    println!("{} matches", matches.len());
+    Ok(())
+}
+
+fn dictionary(input: &str, args: &[String]) -> Result<(), String> {
+    let positions = args.iter().any(|arg| arg == "--positions");
+    let path = args.iter().find(|arg| *arg != "--positions").ok_or("Give a file of words, one per line")?;
+    let contents = std::fs::read_to_string(path).map_err(|error| format!("{}: {}", path, error))?;
+    let words = contents.lines().map(str::trim).filter(|word| !word.is_empty()).collect::<Vec<&str>>();
+
+    let mut matches = Dictionary::new(&words).find(&parse_input(input));
+    matches.sort();
+    let mut counts = vec![0; words.len()];
+    matches.iter().for_each(|found| counts[found.word] += 1);
+
+    for (word, count) in words.iter().zip(counts) {
+        println!("{}: {}", word, count);
+    }
+    if positions {
+        for found in &matches {
+            println!("{} at row {}, column {} going {:?}", words[found.word], found.start.0, found.start.1, found.direction);
+        }
+    }
+    Ok(())
+}
+
+fn bench(args: &[String]) -> Result<(), String> {
+    let size = match args {
+        [] => 10_000,
+        [flag, size] if flag == "--size" => size.parse().map_err(|_| format!("{} is not a size", size))?,
+        _ => return Err(String::from("The only option is --size N")),
+    };
+    let grid = generate_grid(size);
+
+    let timed = |name: &str, part: u8, solve: &dyn Fn() -> usize| {
+        let start = Instant::now();
+        let answer = solve();
+        println!("{:<9}  Part {}  {:>10}  {:?}", name, part, answer, start.elapsed());
+    };
+
+    timed("bitmask", 1, &|| LetterMasks::new(&grid).count_word(XMAS, &Direction::ALL));
+    timed("bitmask", 2, &|| LetterMasks::new(&grid).count_x_mas());
+    timed("default", 1, &|| part_one(&grid));
+    timed("default", 2, &|| part_two(&grid));
+    timed("recursive", 1, &|| part_one_recursive(&grid));
+    timed("recursive", 2, &|| part_two_recursive(&grid));
+    Ok(())
+}
+
+fn part_two(input: &str) -> usize {
+    find_template(&parse_input(input), &Template::parse(X_MAS), Symmetry::Rotations).len()
+}
+
+fn part_two_recursive(input: &str) -> usize {
+    count_crossed_mas_instances(&parse_input(input))
+}
+
+fn count_crossed_mas_instances(grid: &[Vec<char>]) -> usize {
+    let mut total: usize = 0;
+
+    for column_index in 0..grid.len() {
+        for row_index in 0..grid[column_index].len() {
+            if grid[column_index][row_index] == 'A' && is_crossed_mas(grid, (column_index, row_index)) {
+                total += 1;
+            }
+        }
+    }
+
+    total
+}
+
+fn is_crossed_mas(grid: &[Vec<char>], location: (usize, usize)) -> bool {
+    if mas_contained_to_grid_bounds(grid, location) {
+        let forward = [grid[location.0 - 1][location.1 - 1], 'A', grid[location.0 + 1][location.1 + 1]];
+        let backwards = [grid[location.0 - 1][location.1 + 1], 'A', grid[location.0 + 1][location.1 - 1]];
+
+        return MAS_OPTIONS.contains(&forward) && MAS_OPTIONS.contains(&backwards);
+    }
+
+    false
+}
+
+fn mas_contained_to_grid_bounds(grid: &[Vec<char>], location: (usize, usize)) -> bool{
+    [(0,1), (1,0), (0,-1), (-1, 0)]
+        .iter()
+        .all(|vector| new_location_is_in_grid(grid, (location.0 as i32, location.1 as i32), *vector))
+}
+
+fn stencil(input: &str, args: &[String]) -> Result<(), String> {
+    let (mut rotations, mut reflections) = (false, false);
+    let mut pattern = None;
+
+    for arg in args {
+        match arg.as_str() {
+            "--rotations" => rotations = true,
+            "--reflections" => reflections = true,
+            other => pattern = Some(other),
+        }
+    }
+    let symmetry = match (rotations, reflections) {
+        (false, false) => Symmetry::Fixed,
+        (true, false) => Symmetry::Rotations,
+        (false, true) => Symmetry::Reflections,
+        (true, true) => Symmetry::RotationsAndReflections,
+    };
+    let template = Template::parse(pattern.ok_or("Give a pattern like M.S/.A./M.S")?);
+
+    let variants = template.variants(symmetry);
+    let placements = find_template(&parse_input(input), &template, symmetry);
+    for placement in &placements {
+        println!("Variant {} at row {}, column {}", placement.variant, placement.start.0, placement.start.1);
+    }
+    println!("{} placements of {} variants", placements.len(), variants.len());
+    Ok(())
+}
+
+fn parse_input(input: &str) -> Vec<Vec<char>> {
+    input.lines().map(|line| line.chars().collect()).collect()
+}
+
+#[test]
+fn small_input() {
+    assert_eq!(18, part_one(EXAMPLE));
+    assert_eq!(18, part_one_recursive(EXAMPLE));
+    assert_eq!(9, part_two(EXAMPLE));
+    assert_eq!(9, part_two_recursive(EXAMPLE));
+}
+
+
@@ -0,0 +1,173 @@
+use crate::search::Direction::{East, North, NorthEast, NorthWest, South, SouthEast, SouthWest, West};
+
+#[derive(PartialEq, Eq, PartialOrd, Ord, Debug, Clone, Copy, Hash)]
+pub enum Direction {
+    North,
+    NorthEast,
+    East,
+    SouthEast,
+    South,
+    SouthWest,
+    West,
+    NorthWest,
+}
+
+pub struct DirectionSet {
+    pub directions: Vec<Direction>,
+    /// Words may run off one edge of the grid and continue from the opposite edge, with each
+    /// row wrapping at its own length.
+    pub wraparound: bool,
+}
+
+#[derive(PartialEq, Eq, PartialOrd, Ord, Debug)]
+pub struct Match {
+    /// Index into the searched words.
+    pub word: usize,
+    /// Row and column of the first letter.
+    pub start: (usize, usize),
+    pub direction: Direction,
+}
+
+impl Direction {
+    pub const ALL: [Direction; 8] = [North, NorthEast, East, SouthEast, South, SouthWest, West, NorthWest];
+
+    /// Change in row and column for one step.
+    pub fn vector(self) -> (isize, isize) {
+        match self {
+            North => (-1, 0),
+            NorthEast => (-1, 1),
+            East => (0, 1),
+            SouthEast => (1, 1),
+            South => (1, 0),
+            SouthWest => (1, -1),
+            West => (0, -1),
+            NorthWest => (-1, -1),
+        }
+    }
+}
+
+impl DirectionSet {
+    pub fn orthogonal() -> DirectionSet {
+        DirectionSet { directions: vec!(North, East, South, West), wraparound: false }
+    }
+
+    pub fn diagonal() -> DirectionSet {
+        DirectionSet { directions: vec!(NorthEast, SouthEast, SouthWest, NorthWest), wraparound: false }
+    }
+
+    pub fn all() -> DirectionSet {
+        DirectionSet { directions: Direction::ALL.to_vec(), wraparound: false }
+    }
+
+    pub fn wrapping(self) -> DirectionSet {
+        DirectionSet { wraparound: true, ..self }
+    }
+}
+
+/// Every place any of `words` can be read in `grid` along `directions`.
+pub fn find_words(grid: &[Vec<char>], words: &[&str], directions: &DirectionSet) -> Vec<Match> {
+    let words = words.iter().map(|word| word.chars().collect()).collect::<Vec<Vec<char>>>();
+    let mut matches = vec!();
+
+    for row in 0..grid.len() {
+        for column in 0..grid[row].len() {
+            for (index, word) in words.iter().enumerate() {
+                if word.first() != Some(&grid[row][column]) {
+                    continue;
+                }
+
+                for &direction in &directions.directions {
+                    if reads_word(grid, word, (row, column), direction, directions.wraparound) {
+                        matches.push(Match { word: index, start: (row, column), direction });
+                    }
+                }
+            }
+        }
+    }
+
+    matches
+}
+
+fn reads_word(grid: &[Vec<char>], word: &[char], start: (usize, usize), direction: Direction, wraparound: bool) -> bool {
+    let (row_step, column_step) = direction.vector();
+
+    word.iter().enumerate().all(|(index, letter)| {
+        let row = start.0 as isize + row_step * index as isize;
+        let column = start.1 as isize + column_step * index as isize;
+        let cell = if wraparound {
+            let letters = &grid[row.rem_euclid(grid.len() as isize) as usize];
+            (!letters.is_empty()).then(|| &letters[column.rem_euclid(letters.len() as isize) as usize])
+        } else if row >= 0 && column >= 0 {
+            grid.get(row as usize).and_then(|letters| letters.get(column as usize))
+        } else {
+            None
+        };
+
+        cell == Some(letter)
+    })
+}
+
+#[test]
+fn matches_report_their_start_and_direction() {
+    let grid = crate::parse_input("XMAS
+SXOX
+AXMO
+XSAX");
+
+    assert_eq!(
+        vec!(
+            Match { word: 0, start: (0, 0), direction: East },
+            Match { word: 1, start: (1, 1), direction: East },
+            Match { word: 1, start: (1, 3), direction: South },
+            Match { word: 1, start: (1, 3), direction: West },
+            Match { word: 1, start: (3, 3), direction: North },
+        ),
+        find_words(&grid, &["XMAS", "XOX"], &DirectionSet::orthogonal())
+    );
+    assert_eq!(
+        vec!(
+            Match { word: 0, start: (0, 0), direction: SouthEast },
+            Match { word: 0, start: (1, 1), direction: NorthWest },
+            Match { word: 0, start: (2, 1), direction: SouthWest },
+            Match { word: 0, start: (3, 0), direction: NorthEast },
+        ),
+        find_words(&grid, &["XX"], &DirectionSet::diagonal())
+    );
+    assert_eq!(
+        vec!(
+            Match { word: 0, start: (0, 0), direction: South },
+            Match { word: 0, start: (0, 0), direction: West },
+            Match { word: 0, start: (3, 0), direction: East },
+        ),
+        find_words(&grid, &["XSA"], &DirectionSet::orthogonal().wrapping())
+    );
+}
+
+#[test]
+fn ragged_grids_are_searched_row_by_row() {
+    let input = "XMASX\nMM\nAXA\nSMMASAMX\n\nXMAS";
+    let grid = crate::parse_input(input);
+    let words = ["XMAS", "SAMX", "MM", "AX"];
+
+    let mut found = crate::dictionary::Dictionary::new(&words).find(&grid);
+    let mut expected = find_words(&grid, &words, &DirectionSet::all());
+    found.sort();
+    expected.sort();
+    assert_eq!(expected, found);
+
+    let masks = crate::bitmask::LetterMasks::new(input);
+    for (index, word) in words.iter().enumerate() {
+        assert_eq!(
+            expected.iter().filter(|found| found.word == index).count(),
+            masks.count_word(word, &Direction::ALL)
+        );
+    }
+
+    assert_eq!(
+        vec!(
+            Match { word: 0, start: (1, 1), direction: East },
+            Match { word: 0, start: (1, 1), direction: West },
+        ),
+        find_words(&crate::parse_input("AXM\nSX"), &["XS"], &DirectionSet::orthogonal().wrapping())
+    );
+}
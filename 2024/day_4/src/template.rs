@@ -0,0 +1,128 @@
+const WILDCARD: char = '.';
+
+/// A small grid of letters to look for, where `None` matches any letter.
+#[derive(PartialEq, Debug, Clone)]
+pub struct Template {
+    cells: Vec<Vec<Option<char>>>,
+}
+
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub enum Symmetry {
+    Fixed,
+    Rotations,
+    Reflections,
+    RotationsAndReflections,
+}
+
+#[derive(PartialEq, Debug)]
+pub struct Placement {
+    /// Row and column of the template's top left corner.
+    pub start: (usize, usize),
+    /// Index into the template's `variants` for the symmetry searched with.
+    pub variant: usize,
+}
+
+impl Template {
+    /// Rows separated by newlines or `/`, with `.` as a wildcard.
+    pub fn parse(pattern: &str) -> Template {
+        let cells = pattern.split(['\n', '/'])
+            .map(|row| row.trim().chars().map(|letter| (letter != WILDCARD).then_some(letter)).collect())
+            .collect();
+
+        Template { cells }
+    }
+
+    fn rotated(&self) -> Template {
+        let height = self.cells.len();
+        let width = self.cells.iter().map(Vec::len).max().unwrap_or_default();
+        let cells = (0..width)
+            .map(|row| (0..height).map(|column| self.cells[height - 1 - column].get(row).copied().flatten()).collect())
+            .collect();
+
+        Template { cells }
+    }
+
+    fn reflected(&self) -> Template {
+        let cells = self.cells.iter().map(|row| row.iter().rev().copied().collect()).collect();
+
+        Template { cells }
+    }
+
+    /// The distinct orientations of the template, so a symmetric shape is only counted once per place.
+    pub fn variants(&self, symmetry: Symmetry) -> Vec<Template> {
+        let mut orientations = vec!(self.clone());
+        if matches!(symmetry, Symmetry::Rotations | Symmetry::RotationsAndReflections) {
+            (0..3).for_each(|_| orientations.push(orientations.last().unwrap().rotated()));
+        }
+        if matches!(symmetry, Symmetry::Reflections | Symmetry::RotationsAndReflections) {
+            orientations.extend(orientations.iter().map(Template::reflected).collect::<Vec<Template>>());
+        }
+
+        let mut variants: Vec<Template> = vec!();
+        for orientation in orientations {
+            if !variants.contains(&orientation) {
+                variants.push(orientation);
+            }
+        }
+
+        variants
+    }
+
+    fn matches_at(&self, grid: &[Vec<char>], start: (usize, usize)) -> bool {
+        self.cells.iter().enumerate().all(|(row, cells)| {
+            cells.iter().enumerate().all(|(column, cell)| {
+                let letter = grid.get(start.0 + row).and_then(|letters| letters.get(start.1 + column));
+                letter.is_some() && cell.is_none_or(|cell| Some(&cell) == letter)
+            })
+        })
+    }
+}
+
+/// Every place one of the template's variants fits in `grid`.
+pub fn find_template(grid: &[Vec<char>], template: &Template, symmetry: Symmetry) -> Vec<Placement> {
+    let variants = template.variants(symmetry);
+    let mut placements = vec!();
+
+    for row in 0..grid.len() {
+        for column in 0..grid[row].len() {
+            for (variant, orientation) in variants.iter().enumerate() {
+                if orientation.matches_at(grid, (row, column)) {
+                    placements.push(Placement { start: (row, column), variant });
+                }
+            }
+        }
+    }
+
+    placements
+}
+
+#[test]
+fn symmetric_templates_have_fewer_variants() {
+    assert_eq!(4, Template::parse("M.S/.A./M.S").variants(Symmetry::RotationsAndReflections).len());
+    assert_eq!(1, Template::parse(".A./AAA/.A.").variants(Symmetry::RotationsAndReflections).len());
+    assert_eq!(8, Template::parse("AB/C.").variants(Symmetry::RotationsAndReflections).len());
+    assert_eq!(1, Template::parse("AB/C.").variants(Symmetry::Fixed).len());
+    assert_eq!(2, Template::parse("AB/C.").variants(Symmetry::Reflections).len());
+    assert_eq!(Template::parse("CA/.B"), Template::parse("AB/C.").rotated());
+}
+
+#[test]
+fn stencils_are_located() {
+    let grid = crate::parse_input("XMXS
+MASA
+XMXS
+SAXX");
+
+    assert_eq!(
+        vec!(Placement { start: (0, 0), variant: 0 }),
+        find_template(&grid, &Template::parse(".M./MAS/.M."), Symmetry::Fixed)
+    );
+    assert_eq!(
+        vec!(Placement { start: (1, 0), variant: 0 }),
+        find_template(&grid, &Template::parse("MAS"), Symmetry::Rotations)
+    );
+    assert_eq!(
+        vec!(Placement { start: (0, 0), variant: 3 }),
+        find_template(&crate::parse_input("S\nA\nM"), &Template::parse("MAS"), Symmetry::Rotations)
+    );
+}
@@ -0,0 +1,220 @@
+use crate::{CycleError, Instruction};
+use std::cmp::Reverse;
+use std::collections::{BTreeMap, BTreeSet, HashMap, VecDeque};
+
+/// The rules as a graph from each page to the pages that have to come after it.
+pub struct RuleGraph {
+    after: BTreeMap<u32, BTreeSet<u32>>,
+}
+
+/// A rule whose right page also has to come, through other rules, before its left page.
+#[derive(PartialEq, Debug)]
+pub struct Contradiction {
+    pub rule: Instruction,
+    /// Pages from the rule's right page back round to its left.
+    pub path: Vec<u32>,
+}
+
+#[derive(PartialEq, Debug)]
+pub enum OrderError {
+    /// Nothing decides which of these two pages comes first.
+    Incomparable(u32, u32),
+    Cycle(CycleError),
+}
+
+impl RuleGraph {
+    pub fn new(instructions: &[Instruction]) -> RuleGraph {
+        let mut after: BTreeMap<u32, BTreeSet<u32>> = BTreeMap::new();
+        instructions.iter().for_each(|(left, right)| {
+            after.entry(*left).or_default().insert(*right);
+        });
+
+        RuleGraph { after }
+    }
+
+    fn rules(&self) -> impl Iterator<Item = Instruction> + '_ {
+        self.after.iter().flat_map(|(left, rights)| rights.iter().map(|right| (*left, *right)))
+    }
+
+    /// Just the rules between `pages`, the only ones that matter when printing them.
+    pub fn among(&self, pages: &[u32]) -> RuleGraph {
+        let pages = pages.iter().copied().collect::<BTreeSet<u32>>();
+        let rules = self.rules().filter(|(left, right)| pages.contains(left) && pages.contains(right));
+
+        RuleGraph::new(&rules.collect::<Vec<Instruction>>())
+    }
+
+    /// The shortest chain of rules from `from` to `to`, if any, never using `skip`. When
+    /// they're the same page this is the shortest loop back to it.
+    fn path_without(&self, from: u32, to: u32, skip: Option<Instruction>) -> Option<Vec<u32>> {
+        let mut previous = HashMap::new();
+        let mut queue = VecDeque::from([from]);
+
+        while let Some(page) = queue.pop_front() {
+            for &next in self.after.get(&page).into_iter().flatten() {
+                if skip == Some((page, next)) {
+                    continue;
+                }
+                if next == to {
+                    let mut path = vec!(to, page);
+                    while *path.last().unwrap() != from {
+                        path.push(previous[path.last().unwrap()]);
+                    }
+                    path.reverse();
+                    return Some(path);
+                }
+                if next != from && !previous.contains_key(&next) {
+                    previous.insert(next, page);
+                    queue.push_back(next);
+                }
+            }
+        }
+
+        None
+    }
+
+    fn path(&self, from: u32, to: u32) -> Option<Vec<u32>> {
+        self.path_without(from, to, None)
+    }
+
+    /// Some loop of rules, if there is one.
+    fn cycle(&self) -> Option<CycleError> {
+        self.after.keys().find_map(|&page| {
+            let mut pages = self.path(page, page)?;
+            pages.pop();
+            Some(CycleError { pages })
+        })
+    }
+
+    /// Whether the rules between the pages of `update`, followed through any number of them,
+    /// put `left` before `right`.
+    pub fn precedes(&self, left: u32, right: u32, update: &[u32]) -> bool {
+        let mut pages = update.to_vec();
+        pages.extend([left, right]);
+
+        self.among(&pages).path(left, right).is_some()
+    }
+
+    /// Every page each page has to come before, directly or through other rules.
+    pub fn closure(&self) -> BTreeMap<u32, BTreeSet<u32>> {
+        self.after.keys()
+            .map(|&page| {
+                let mut reachable = BTreeSet::new();
+                let mut queue = VecDeque::from([page]);
+                while let Some(current) = queue.pop_front() {
+                    for &next in self.after.get(&current).into_iter().flatten() {
+                        if reachable.insert(next) {
+                            queue.push_back(next);
+                        }
+                    }
+                }
+                (page, reachable)
+            })
+            .collect()
+    }
+
+    /// Rules already implied by a chain of other rules. Once the rules loop, dropping one
+    /// can break the chain implying another, so there's no answer.
+    pub fn redundant(&self) -> Result<Vec<Instruction>, CycleError> {
+        if let Some(cycle) = self.cycle() {
+            return Err(cycle);
+        }
+
+        Ok(self.rules().filter(|&(left, right)| self.path_without(left, right, Some((left, right))).is_some()).collect())
+    }
+
+    /// The rules left once the redundant ones are dropped, which imply exactly the same
+    /// orderings as the full set.
+    pub fn reduction(&self) -> Result<Vec<Instruction>, CycleError> {
+        let redundant = self.redundant()?;
+
+        Ok(self.rules().filter(|rule| !redundant.contains(rule)).collect())
+    }
+
+    /// Rules between the pages of `update` that the others between them contradict.
+    pub fn contradictions(&self, update: &[u32]) -> Vec<Contradiction> {
+        let graph = self.among(update);
+
+        graph.rules()
+            .filter_map(|(left, right)| graph.path(right, left).map(|path| Contradiction { rule: (left, right), path }))
+            .collect()
+    }
+
+    /// The only order of `pages` the rules between them allow, if they decide every pair.
+    pub fn total_order(&self, pages: &[u32]) -> Result<Vec<u32>, OrderError> {
+        let graph = self.among(pages);
+        if let Some(cycle) = graph.cycle() {
+            return Err(OrderError::Cycle(cycle));
+        }
+        let closure = graph.closure();
+        let precedes = |left: u32, right: u32| closure.get(&left).is_some_and(|after| after.contains(&right));
+
+        let mut order = pages.to_vec();
+        order.sort_by_key(|&page| Reverse(pages.iter().filter(|&&other| precedes(page, other)).count()));
+
+        match order.windows(2).find(|pair| !precedes(pair[0], pair[1])) {
+            Some(pair) => Err(OrderError::Incomparable(pair[0], pair[1])),
+            None => Ok(order),
+        }
+    }
+}
+
+#[test]
+fn rules_are_analysed() {
+    let graph = RuleGraph::new(&[(1, 2), (2, 3), (1, 3), (3, 4), (5, 6), (6, 5)]);
+
+    assert!(graph.precedes(1, 4, &[2, 3]));
+    assert!(!graph.precedes(1, 4, &[2]));
+    assert!(!graph.precedes(4, 1, &[2, 3]));
+    assert_eq!(Some(vec!(1, 3, 4)), graph.path(1, 4));
+    assert_eq!(BTreeSet::from([2, 3, 4]), graph.closure()[&1]);
+    assert_eq!(Err(CycleError { pages: vec!(5, 6) }), graph.redundant());
+    assert_eq!(Ok(vec!((1, 3))), graph.among(&[1, 2, 3, 4]).redundant());
+    assert_eq!(Ok(vec!((1, 2), (2, 3), (3, 4))), graph.among(&[1, 2, 3, 4]).reduction());
+    assert_eq!(
+        vec!(Contradiction { rule: (5, 6), path: vec!(6, 5) }, Contradiction { rule: (6, 5), path: vec!(5, 6) }),
+        graph.contradictions(&[1, 5, 6])
+    );
+    assert_eq!(Ok(vec!(1, 2, 3, 4)), graph.total_order(&[4, 2, 3, 1]));
+    assert_eq!(Ok(vec!(1, 3)), graph.total_order(&[3, 1]));
+    assert_eq!(Err(OrderError::Incomparable(4, 5)), graph.total_order(&[4, 5]));
+    assert_eq!(Err(OrderError::Cycle(CycleError { pages: vec!(5, 6) })), graph.total_order(&[6, 5]));
+}
+
+#[test]
+fn orders_follow_chains_of_rules_between_the_pages() {
+    let graph = RuleGraph::new(&[(1, 2), (2, 3), (4, 5), (5, 6), (6, 4), (7, 7)]);
+
+    assert!(graph.precedes(1, 3, &[2]));
+    assert_eq!(Ok(vec!(1, 2, 3)), graph.total_order(&[2, 3, 1]));
+    assert_eq!(Err(OrderError::Incomparable(3, 1)), graph.total_order(&[3, 1]));
+    assert_eq!(Ok(vec!(6, 4)), graph.total_order(&[4, 6]));
+    assert_eq!(Err(OrderError::Cycle(CycleError { pages: vec!(4, 5, 6) })), graph.total_order(&[6, 4, 5]));
+    assert_eq!(Some(vec!(7, 7)), graph.path(7, 7));
+    assert_eq!(vec!(Contradiction { rule: (7, 7), path: vec!(7, 7) }), graph.contradictions(&[1, 7]));
+}
+
+#[test]
+fn cyclic_rules_still_order_pages_they_dont_loop_between() {
+    let graph = RuleGraph::new(&[(1, 2), (2, 3), (3, 4), (4, 1), (1, 3)]);
+
+    assert!(graph.precedes(1, 3, &[]));
+    assert!(!graph.precedes(3, 1, &[2]));
+    assert!(graph.precedes(3, 1, &[2, 4]));
+    assert_eq!(Ok(vec!(1, 2, 3)), graph.total_order(&[3, 1, 2]));
+    assert_eq!(Ok(vec!(4, 1)), graph.total_order(&[1, 4]));
+    assert!(graph.contradictions(&[1, 2, 3]).is_empty());
+    assert_eq!(
+        vec!(
+            Contradiction { rule: (1, 2), path: vec!(2, 3, 4, 1) },
+            Contradiction { rule: (1, 3), path: vec!(3, 4, 1) },
+            Contradiction { rule: (2, 3), path: vec!(3, 4, 1, 2) },
+            Contradiction { rule: (3, 4), path: vec!(4, 1, 3) },
+            Contradiction { rule: (4, 1), path: vec!(1, 3, 4) },
+        ),
+        graph.contradictions(&[1, 2, 3, 4])
+    );
+    assert_eq!(Err(CycleError { pages: vec!(1, 3, 4) }), graph.redundant());
+    assert_eq!(Err(CycleError { pages: vec!(1, 3, 4) }), graph.reduction());
+    assert_eq!(Ok(vec!((1, 3))), graph.among(&[1, 2, 3]).redundant());
+}
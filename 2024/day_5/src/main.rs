@@ -0,0 +1,367 @@
+mod analysis;
+
+use crate::analysis::{OrderError, RuleGraph};
+use aoc::{Answer, Command, Solution};
+use std::cmp::Reverse;
+use std::collections::{BTreeMap, BinaryHeap, HashMap, HashSet};
+use std::fmt::{Display, Formatter};
+
+type Instruction = (u32, u32);
+
+const SMALL_PAGES: u32 = 100;
+
+/// The rules compiled for lookup: whether the first page has to come before the second.
+enum RuleIndex {
+    /// Bit `b` of `before[a]` is set for a rule `a|b`, when every page is below `SMALL_PAGES`.
+    Small { before: Vec<u128> },
+    Large { before: HashMap<u32, HashSet<u32>> },
+}
+
+/// Rules between the pages of an update that go round in a loop, so they can't all hold.
+#[derive(PartialEq, Debug)]
+struct CycleError {
+    /// Each page has to come before the next, and the last before the first.
+    pages: Vec<u32>,
+}
+
+struct Day5;
+
+impl Solution for Day5 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 5;
+
+    fn part_one(input: &str) -> impl Into<Answer> {
+        part_one(input)
+    }
+
+    fn part_two(input: &str) -> Option<impl Into<Answer>> {
+        Some(part_two(input))
+    }
+
+    fn commands() -> Vec<Command> {
+        vec![Command {
+            name: "rules",
+            about: "Analyse the rules: precedes X Y [P,Q,...], closure, redundant, reduction or contradictions [P,Q,...], total P,Q,...",
+            run: rules,
+        }]
+    }
+}
+
+fn main() {
+    aoc::run::<Day5>();
+}
+
+fn rules(input: &str, args: &[String]) -> Result<(), String> {
+    let input = input.replace("\r\n", "\n");
+    let parts: Vec<&str> = input.split("\n\n").collect();
+    let (instructions, updates) = parse_input(&parts);
+    let graph = RuleGraph::new(&instructions);
+    let join = |pages: &[u32], separator: &str| pages.iter().map(u32::to_string).collect::<Vec<String>>().join(separator);
+    let page = |value: Option<&String>| value.and_then(|value| value.parse::<u32>().ok()).ok_or("Expected a page number");
+    let update = |value: Option<&String>| {
+        value.map(|value| {
+            value.split(',')
+                .map(|page| page.parse().map_err(|_| format!("{} is not a page number", page)))
+                .collect::<Result<Vec<u32>, String>>()
+        })
+        .transpose()
+    };
+    let contradicted = |updates: &[Vec<u32>]| {
+        updates.iter().flat_map(|update| graph.contradictions(update))
+            .map(|contradiction| (contradiction.rule, contradiction.path))
+            .collect::<BTreeMap<Instruction, Vec<u32>>>()
+    };
+
+    match args.first().map(String::as_str) {
+        Some("precedes") => {
+            let (left, right) = (page(args.get(1))?, page(args.get(2))?);
+            let update = update(args.get(3))?.unwrap_or_default();
+            let verdict = if graph.precedes(left, right, &update) {"has to come"} else {"doesn't have to come"};
+            println!("{} {} before {}", left, verdict, right);
+        }
+        Some("closure") => {
+            for (page, after) in graph.closure() {
+                println!("{}: {}", page, join(&after.into_iter().collect::<Vec<u32>>(), ","));
+            }
+        }
+        Some(kind @ ("redundant" | "reduction")) => {
+            let graph = update(args.get(1))?.map_or_else(|| RuleGraph::new(&instructions), |pages| graph.among(&pages));
+            match if kind == "redundant" {graph.redundant()} else {graph.reduction()} {
+                Ok(rules) => rules.iter().for_each(|(left, right)| println!("{}|{}", left, right)),
+                Err(cycle) => println!("No {}: {}", kind, cycle),
+            }
+        }
+        Some("contradictions") => {
+            let updates = update(args.get(1))?.map_or(updates, |pages| vec!(pages));
+            for ((left, right), path) in contradicted(&updates) {
+                println!("{}|{} contradicted by {}", left, right, join(&path, " -> "));
+            }
+        }
+        Some("total") => {
+            let pages = update(args.get(1))?.ok_or("Expected pages like 75,47,61")?;
+            match graph.total_order(&pages) {
+                Ok(order) => println!("Total: {}", join(&order, ",")),
+                Err(OrderError::Incomparable(left, right)) => println!("Not total: no rule orders {} and {}", left, right),
+                Err(OrderError::Cycle(cycle)) => println!("Not total: {}", cycle),
+            }
+        }
+        Some(other) => return Err(format!("Unknown analysis {}", other)),
+        None => {
+            println!("{} rules, {} contradicted within an update", instructions.len(), contradicted(&updates).len());
+            match graph.redundant() {
+                Ok(redundant) => println!("{} redundant", redundant.len()),
+                Err(cycle) => println!("No redundant rules: {}", cycle),
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn part_one(input: &str) -> u32 {
+    let input = input.replace("\r\n", "\n");
+    let parts: Vec<&str> = input.split("\n\n").collect();
+
+    let (instructions, pages) = parse_input(&parts);
+    apply_rules(&instructions, &pages)
+}
+
+fn part_two(input: &str) -> Result<u32, CycleError> {
+    let input = input.replace("\r\n", "\n");
+    let parts: Vec<&str> = input.split("\n\n").collect();
+
+    let (instructions, pages) = parse_input(&parts);
+    fix_broken(&instructions, &pages)
+}
+
+fn apply_rules(instructions: &[Instruction], pages: &[Vec<u32>]) -> u32 {
+    let rules = RuleIndex::new(instructions);
+
+    pages.iter()
+        .filter(|page| page_is_valid(page, &rules))
+        .map(|page| get_middle_number(page))
+        .sum()
+}
+
+impl RuleIndex {
+    fn new(instructions: &[Instruction]) -> RuleIndex {
+        if instructions.iter().all(|(left, right)| *left < SMALL_PAGES && *right < SMALL_PAGES) {
+            let mut before = vec![0; SMALL_PAGES as usize];
+            instructions.iter().for_each(|(left, right)| before[*left as usize] |= 1 << right);
+
+            RuleIndex::Small { before }
+        } else {
+            let mut before: HashMap<u32, HashSet<u32>> = HashMap::new();
+            instructions.iter().for_each(|(left, right)| {
+                before.entry(*left).or_default().insert(*right);
+            });
+
+            RuleIndex::Large { before }
+        }
+    }
+
+    /// The pages `left` has to come before.
+    fn successors(&self, left: u32) -> Box<dyn Iterator<Item = u32> + '_> {
+        match self {
+            RuleIndex::Small { before } => {
+                let mut bits = before.get(left as usize).copied().unwrap_or_default();
+                Box::new(std::iter::from_fn(move || {
+                    let right = (bits != 0).then(|| bits.trailing_zeros())?;
+                    bits &= bits - 1;
+                    Some(right)
+                }))
+            }
+            RuleIndex::Large { before } => Box::new(before.get(&left).into_iter().flatten().copied()),
+        }
+    }
+
+    /// The rule broken by the earliest page that has to come before a page already printed.
+    fn first_violation(&self, page: &[u32]) -> Option<Instruction> {
+        let mut positions = HashMap::new();
+        page.iter().enumerate().for_each(|(index, number)| {
+            positions.entry(*number).or_insert(index);
+        });
+
+        page.iter().enumerate().find_map(|(index, later)| {
+            self.successors(*later)
+                .filter_map(|right| positions.get(&right).copied().filter(|&earlier| earlier < index))
+                .min()
+                .map(|earlier| (*later, page[earlier]))
+        })
+    }
+}
+
+fn page_is_valid(page: &[u32], rules: &RuleIndex) -> bool {
+    rules.first_violation(page).is_none()
+}
+
+fn fix_broken(instructions: &[Instruction], pages: &[Vec<u32>]) -> Result<u32, CycleError> {
+    let rules = RuleIndex::new(instructions);
+
+    pages.iter()
+        .filter(|page| !page_is_valid(page, &rules))
+        .map(|page| fix_page(page, instructions).map(|page| get_middle_number(&page)))
+        .sum()
+}
+
+/// Orders the update with a topological sort of the rules between its pages, keeping pages
+/// the rules don't constrain in their original order.
+fn fix_page(page: &[u32], instructions: &[Instruction]) -> Result<Vec<u32>, CycleError> {
+    let positions = page.iter().enumerate().map(|(index, number)| (*number, index)).collect::<HashMap<u32, usize>>();
+    let mut successors = vec![vec!(); page.len()];
+    let mut predecessors = vec![vec!(); page.len()];
+    let mut blocked_by = vec![0; page.len()];
+
+    for (left, right) in instructions {
+        if let (Some(&left), Some(&right)) = (positions.get(left), positions.get(right)) {
+            successors[left].push(right);
+            predecessors[right].push(left);
+            blocked_by[right] += 1;
+        }
+    }
+
+    let mut ready = (0..page.len()).filter(|&index| blocked_by[index] == 0).map(Reverse).collect::<BinaryHeap<Reverse<usize>>>();
+    let mut fixed_page = vec!();
+    while let Some(Reverse(index)) = ready.pop() {
+        fixed_page.push(page[index]);
+        for &next in &successors[index] {
+            blocked_by[next] -= 1;
+            if blocked_by[next] == 0 {
+                ready.push(Reverse(next));
+            }
+        }
+    }
+
+    if fixed_page.len() < page.len() {
+        return Err(find_cycle(page, &predecessors, &blocked_by));
+    }
+
+    Ok(fixed_page)
+}
+
+/// Every page left unsorted still waits on another unsorted page, so following those
+/// back from any of them has to come round to a page already seen.
+fn find_cycle(page: &[u32], predecessors: &[Vec<usize>], blocked_by: &[usize]) -> CycleError {
+    let mut seen = HashMap::new();
+    let mut path = vec!();
+    let mut current = (0..page.len()).find(|&index| blocked_by[index] > 0).unwrap();
+
+    while !seen.contains_key(&current) {
+        seen.insert(current, path.len());
+        path.push(current);
+        current = *predecessors[current].iter().find(|&&previous| blocked_by[previous] > 0).unwrap();
+    }
+
+    CycleError { pages: path[seen[&current]..].iter().rev().map(|&index| page[index]).collect() }
+}
+
+impl Display for CycleError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let pages = self.pages.iter().chain(self.pages.first()).map(u32::to_string).collect::<Vec<String>>();
+        write!(f, "Pages can't be ordered, the rules form a cycle: {}", pages.join(" -> "))
+    }
+}
+
+fn get_middle_number(page: &[u32]) -> u32 {
+    page[page.len() / 2]
+}
+
+fn parse_input(parts: &[&str]) -> (Vec<Instruction>, Vec<Vec<u32>>) {
+    let instructions = parts[0]
+        .trim()
+        .lines()
+        .filter(|line| !line.is_empty())
+        .map(|line| {
+            let numbers: Vec<&str> = line.split('|').collect();
+            (numbers[0].parse().unwrap(), numbers[1].parse().unwrap())
+        })
+        .collect::<Vec<Instruction>>();
+
+    let pages = parts[1]
+        .lines()
+        .map(|line| {
+            line.split(',')
+                .map(|number| number.parse().unwrap())
+                .collect()
+        })
+        .collect::<Vec<Vec<u32>>>();
+
+    (instructions, pages)
+}
+
+#[test]
+fn small_input() {
+    let input = "47|53
+97|13
+97|61
+97|47
+75|29
+61|13
+75|53
+29|13
+97|29
+53|29
+61|53
+97|53
+61|29
+47|13
+75|47
+97|75
+47|61
+75|61
+47|29
+75|13
+53|13
+
+75,47,61,53,29
+97,61,53,29,13
+75,29,13
+75,97,47,61,53
+61,13,29
+97,13,75,29,47";
+
+    let parts: Vec<&str> = input.split("\n\n").collect();
+
+    let (instructions, pages) = parse_input(&parts);
+
+    assert_eq!(143, apply_rules(&instructions, &pages));
+
+    assert_eq!(Ok(vec!(97, 75, 47, 61, 53)), fix_page(&pages[3], &instructions));
+    assert_eq!(Ok(vec!(61, 29, 13)), fix_page(&pages[4], &instructions));
+    assert_eq!(Ok(vec!(97, 75, 47, 29, 13)), fix_page(&pages[5], &instructions));
+
+    assert_eq!(Ok(123), fix_broken(&instructions, &pages));
+}
+
+#[test]
+fn contradictory_rules_name_the_cycle() {
+    let instructions = vec!((1, 2), (2, 3), (3, 4), (4, 2), (5, 1));
+
+    assert_eq!(Err(CycleError { pages: vec!(2, 3, 4) }), fix_page(&[5, 4, 3, 2, 1], &instructions));
+    assert_eq!(Ok(vec!(5, 1, 2, 3)), fix_page(&[3, 2, 1, 5], &instructions));
+    assert_eq!(Err(CycleError { pages: vec!(2, 1) }), part_two("1|2\n2|1\n\n1,2\n"));
+    assert_eq!(
+        "Pages can't be ordered, the rules form a cycle: 2 -> 3 -> 4 -> 2",
+        CycleError { pages: vec!(2, 3, 4) }.to_string()
+    );
+}
+
+#[test]
+fn violations_are_found_in_either_direction() {
+    for instructions in [vec!((47, 53), (97, 13), (75, 47)), vec!((47, 53), (97, 13), (75, 47), (1000, 2000))] {
+        let rules = RuleIndex::new(&instructions);
+
+        assert_eq!(None, rules.first_violation(&[75, 47, 53, 97, 13]));
+        assert_eq!(Some((97, 13)), rules.first_violation(&[13, 75, 97]));
+        assert_eq!(Some((47, 53)), rules.first_violation(&[75, 53, 47, 13, 97]));
+        assert_eq!(Some((75, 47)), rules.first_violation(&[47, 75, 13, 97, 53]));
+        assert!(!page_is_valid(&[53, 47], &rules));
+        assert!(page_is_valid(&[47, 53], &rules));
+    }
+
+    assert!(matches!(RuleIndex::new(&[(99, 98)]), RuleIndex::Small { .. }));
+    assert!(matches!(RuleIndex::new(&[(100, 98)]), RuleIndex::Large { .. }));
+    assert_eq!(vec!(98), RuleIndex::new(&[(100, 98)]).successors(100).collect::<Vec<u32>>());
+    assert_eq!(vec!(3, 98), RuleIndex::new(&[(99, 98), (99, 3)]).successors(99).collect::<Vec<u32>>());
+    assert_eq!(0, RuleIndex::new(&[(99, 98)]).successors(200).count());
+}
@@ -0,0 +1,79 @@
+use crate::Direction::{East, North, South, West};
+use crate::{Direction, Visited};
+
+type Location = (i32, i32);
+
+/// The obstacles of each row and column in order, so the guard can go straight to the next
+/// one instead of walking a square at a time.
+pub struct Obstacles {
+    /// Columns of the obstacles in each row.
+    rows: Vec<Vec<i32>>,
+    /// Rows of the obstacles in each column.
+    columns: Vec<Vec<i32>>,
+}
+
+impl Obstacles {
+    pub fn new(grid: &[Vec<char>]) -> Obstacles {
+        let mut rows = vec![vec![]; grid.len()];
+        let mut columns = vec![vec![]; grid.first().map_or(0, Vec::len)];
+
+        for (y, line) in grid.iter().enumerate() {
+            for (x, _) in line.iter().enumerate().filter(|(_, ch)| **ch == '#') {
+                rows[y].push(x as i32);
+                columns[x].push(y as i32);
+            }
+        }
+
+        Obstacles { rows, columns }
+    }
+
+    /// Where the guard stops in front of the next obstacle, or `None` if they walk off the map.
+    /// `extra` is one more obstacle that isn't on the map.
+    pub fn jump(&self, location: Location, direction: Direction, extra: Option<Location>) -> Option<Location> {
+        let (x, y) = location;
+        let vertical = matches!(direction, North | South);
+        let (line, along) = if vertical {(&self.columns[x as usize], y)} else {(&self.rows[y as usize], x)};
+        let extra = extra
+            .filter(|&(extra_x, extra_y)| if vertical {extra_x == x} else {extra_y == y})
+            .map(|(extra_x, extra_y)| if vertical {extra_y} else {extra_x});
+        let split = line.partition_point(|&position| position < along);
+
+        let obstacle = match direction {
+            North | West => line[..split].last().copied().into_iter().chain(extra.filter(|&position| position < along)).max(),
+            South | East => line.get(split).copied().into_iter().chain(extra.filter(|&position| position > along)).min(),
+        }?;
+
+        let (step_x, step_y) = direction.get_vector();
+        Some(if vertical {(x, obstacle - step_y)} else {(obstacle - step_x, y)})
+    }
+
+    /// Whether the guard ends up walking in circles from `location`.
+    pub fn loops(&self, mut location: Location, mut direction: Direction, extra: Option<Location>) -> bool {
+        let mut turns = Visited::new(self.columns.len(), self.rows.len());
+
+        while let Some(stop) = self.jump(location, direction, extra) {
+            if !turns.insert(stop, direction) {
+                return true;
+            }
+            location = stop;
+            direction = direction.next();
+        }
+
+        false
+    }
+}
+
+#[test]
+fn guard_jumps_to_the_next_obstacle() {
+    let obstacles = Obstacles::new(&crate::parse_input(crate::EXAMPLE));
+
+    assert_eq!(Some((4, 1)), obstacles.jump((4, 6), North, None));
+    assert_eq!(Some((8, 1)), obstacles.jump((4, 1), East, None));
+    assert_eq!(Some((8, 6)), obstacles.jump((8, 1), South, None));
+    assert_eq!(None, obstacles.jump((7, 7), South, None));
+    assert_eq!(Some((7, 8)), obstacles.jump((7, 7), South, Some((7, 9))));
+    assert_eq!(Some((4, 4)), obstacles.jump((4, 6), North, Some((4, 3))));
+    assert_eq!(Some((4, 1)), obstacles.jump((4, 6), North, Some((4, 7))));
+    assert!(!obstacles.loops((4, 6), North, None));
+    assert!(obstacles.loops((4, 6), North, Some((3, 6))));
+}
@@ -0,0 +1,367 @@
+mod jump;
+mod obstruction;
+mod patrol;
+
+use aoc::{Answer, Command, Implementation, Solution};
+use crate::obstruction::{find_obstructions, looping_steps, render};
+use crate::patrol::{find_guards, patrol, Edge, TurnPolicy};
+use crate::Direction::{East, North, South, West};
+use std::collections::HashSet;
+
+type Track = Vec<((i32, i32), Direction)>;
+
+#[cfg(test)]
+const EXAMPLE: &str = "....#.....
+.........#
+..........
+..#.......
+.......#..
+..........
+.#..^.....
+........#.
+#.........
+......#...";
+
+#[derive(Eq, PartialEq, Clone, Copy, Hash, Debug)]
+enum Direction {
+    North,
+    East,
+    South,
+    West,
+}
+
+impl Direction {
+    fn get_vector(&self) -> (i32, i32) {
+        match self {
+            North => (0, -1),
+            East => (1, 0),
+            South => (0, 1),
+            West => (-1, 0),
+        }
+    }
+
+    fn next(&self) -> Direction {
+        match self {
+            North => East,
+            East => South,
+            South => West,
+            West => North,
+        }
+    }
+}
+
+/// How a walk ends.
+#[derive(PartialEq, Debug)]
+enum Outcome {
+    /// The guard walks off the map after this many steps.
+    Exits { steps: usize },
+    /// At step `cycle_start + cycle_length` the guard is back where they were at step
+    /// `cycle_start`, facing the same way, and goes round again for ever.
+    Loops { cycle_start: usize, cycle_length: usize },
+}
+
+/// One bit for each square and each direction the guard can face on it.
+struct Visited {
+    bits: Vec<u64>,
+    width: usize,
+}
+
+impl Visited {
+    fn new(width: usize, height: usize) -> Visited {
+        Visited { bits: vec![0; (width * height * 4).div_ceil(64)], width }
+    }
+
+    /// Marks the guard as having been at `location` facing `direction`, returning whether
+    /// they hadn't been before.
+    fn insert(&mut self, location: (i32, i32), direction: Direction) -> bool {
+        let bit = (location.1 as usize * self.width + location.0 as usize) * 4 + direction as usize;
+        let (word, mask) = (bit / 64, 1 << (bit % 64));
+        let new = self.bits[word] & mask == 0;
+        self.bits[word] |= mask;
+
+        new
+    }
+}
+
+struct Day6;
+
+impl Solution for Day6 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 6;
+
+    fn part_one(input: &str) -> impl Into<Answer> {
+        part_one(input)
+    }
+
+    fn part_two(input: &str) -> Option<impl Into<Answer>> {
+        Some(part_two(input))
+    }
+
+    fn alternatives() -> Vec<Implementation> {
+        vec![Implementation { name: "brute_force", part: 2, solve: |input| part_two_brute_force(input).into() }]
+    }
+
+    fn commands() -> Vec<Command> {
+        vec![
+            Command {
+                name: "obstructions",
+                about: "List where an obstruction makes the guard loop, or draw one with --render N [--plain]",
+                run: obstructions,
+            },
+            Command {
+                name: "patrol",
+                about: "Walk every ^>v< guard [--turn right|left|reverse,...] [--edge exit|wall|wrap]",
+                run: patrol_command,
+            },
+        ]
+    }
+}
+
+fn main() {
+    aoc::run::<Day6>();
+}
+
+fn part_one(input: &str) -> usize {
+    let grid = parse_input(input);
+    let starting_point = find_start(&grid);
+    let (track, _) = get_track(&grid, &starting_point);
+
+    HashSet::<(i32, i32)>::from_iter(track.iter().map(|value| value.0)).len()
+}
+
+fn part_two(input: &str) -> usize {
+    let grid = parse_input(input);
+    let starting_point = find_start(&grid);
+    let (track, _) = get_track(&grid, &starting_point);
+
+    looping_steps(&grid, &track).len()
+}
+
+fn part_two_brute_force(input: &str) -> usize {
+    let grid = parse_input(input);
+    let starting_point = find_start(&grid);
+    let (track, _) = get_track(&grid, &starting_point);
+
+    count_potential_obstructions(&grid, track)
+}
+
+fn obstructions(input: &str, args: &[String]) -> Result<(), String> {
+    let grid = parse_input(input);
+    let (track, _) = get_track(&grid, &find_start(&grid));
+    let obstructions = find_obstructions(&grid, &track);
+
+    match args.iter().position(|arg| arg == "--render") {
+        Some(flag) => {
+            let number = args.get(flag + 1)
+                .and_then(|value| value.parse::<usize>().ok())
+                .ok_or("Expected --render N")?;
+            let obstruction = obstructions.get(number)
+                .ok_or(format!("There are only {} obstructions", obstructions.len()))?;
+            print!("{}", render(&grid, &track, obstruction, !args.iter().any(|arg| arg == "--plain")));
+        }
+        None => {
+            for (number, obstruction) in obstructions.iter().enumerate() {
+                println!(
+                    "{}: {},{} reached at step {}, loop of {} steps",
+                    number,
+                    obstruction.position.0,
+                    obstruction.position.1,
+                    obstruction.first_step,
+                    obstruction.loop_path.len()
+                );
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn patrol_command(input: &str, args: &[String]) -> Result<(), String> {
+    let option = |name: &str| args.iter().position(|arg| arg == name).and_then(|flag| args.get(flag + 1));
+    let policies = match option("--turn") {
+        Some(names) => names.split(',')
+            .map(|name| TurnPolicy::parse(name).ok_or(format!("Unknown turn policy {}", name)))
+            .collect::<Result<Vec<TurnPolicy>, String>>()?,
+        None => vec![],
+    };
+    let edge = match option("--edge") {
+        Some(name) => Edge::parse(name).ok_or(format!("Unknown edge {}", name))?,
+        None => Edge::Exit,
+    };
+
+    let grid = parse_input(input);
+    let guards = find_guards(&grid, &policies);
+    if guards.is_empty() {
+        return Err(String::from("No guards on the map, mark them with ^, >, v or <"));
+    }
+    let patrol = patrol(&grid, &guards, edge)?;
+
+    for (guard, outcome) in guards.iter().zip(&patrol.outcomes) {
+        let ending = match outcome {
+            Outcome::Exits { steps } => format!("leaves after {} steps", steps),
+            Outcome::Loops { cycle_start, cycle_length } => {
+                format!("loops every {} steps from step {}", cycle_length, cycle_start)
+            }
+        };
+        println!("{:?} guard at {},{} turning {:?} {}", guard.direction, guard.start.0, guard.start.1, guard.policy, ending);
+    }
+    println!("{} squares covered{}", patrol.covered, if patrol.loops() {", the patrol never ends"} else {""});
+
+    Ok(())
+}
+
+fn parse_input(input: &str) -> Vec<Vec<char>> {
+    input.lines().map(|line| line.chars().collect()).collect()
+}
+
+fn find_start(grid: &[Vec<char>]) -> (usize, usize) {
+    for (y, line) in grid.iter().enumerate() {
+        for (x, ch) in line.iter().enumerate() {
+            if *ch == '^' {
+                return (x, y);
+            }
+        }
+    }
+    panic!("Could not find start point");
+}
+
+fn get_track(
+    grid: &[Vec<char>],
+    starting_point: &(usize, usize),
+) -> (Track, Outcome) {
+    walk(grid, (starting_point.0 as i32, starting_point.1 as i32), North)
+}
+
+/// Every step from `location` until the guard leaves the map or first gets back to a step
+/// they've taken before.
+fn walk(
+    grid: &[Vec<char>],
+    mut current_location: (i32, i32),
+    mut current_direction: Direction,
+) -> (Track, Outcome) {
+    let mut visited_locations = vec![];
+    let mut visited = Visited::new(grid[0].len(), grid.len());
+
+    while location_is_in_grid(&current_location, grid) {
+        if !visited.insert(current_location, current_direction) {
+            let cycle_start = visited_locations.iter()
+                .position(|&step| step == (current_location, current_direction))
+                .unwrap();
+            let cycle_length = visited_locations.len() - cycle_start;
+            return (visited_locations, Outcome::Loops { cycle_start, cycle_length });
+        }
+        visited_locations.push((current_location, current_direction));
+        (current_location, current_direction) =
+            get_new_location(grid, current_location, current_direction)
+    }
+
+    let steps = visited_locations.len();
+    (visited_locations, Outcome::Exits { steps })
+}
+
+/// Indexes into `track` of the first step onto each square after the start, the only places
+/// an obstruction can go without changing the route before it.
+fn first_visits(track: &[((i32, i32), Direction)]) -> Vec<usize> {
+    let mut seen = HashSet::from([track[0].0]);
+
+    (1..track.len()).filter(|&index| seen.insert(track[index].0)).collect()
+}
+
+fn location_is_in_grid(location: &(i32, i32), grid: &[Vec<char>]) -> bool {
+    location.1 >= 0
+        && location.0 >= 0
+        && location.1 < grid.len() as i32
+        && location.0 < grid[0].len() as i32
+}
+
+fn get_new_location(
+    grid: &[Vec<char>],
+    current_location: (i32, i32),
+    mut current_direction: Direction,
+) -> ((i32, i32), Direction) {
+    for _ in 0..4 {
+        let transformation = current_direction.get_vector();
+        let new_location = (
+            current_location.0 + transformation.0,
+            current_location.1 + transformation.1,
+        );
+
+        if !location_is_in_grid(&new_location, grid)
+            || grid[new_location.1 as usize][new_location.0 as usize] != '#'
+        {
+            return (new_location, current_direction);
+        }
+        current_direction = current_direction.next();
+    }
+
+    // Walled in on every side, so the guard turns on the spot for ever.
+    (current_location, current_direction)
+}
+
+fn count_potential_obstructions(
+    grid: &[Vec<char>],
+    track: Vec<((i32, i32), Direction)>,
+) -> usize {
+    first_visits(&track)
+        .into_iter()
+        .filter(|&index| blocker_would_create_loop(grid, &track, index))
+        .count()
+}
+
+fn blocker_would_create_loop(
+    grid: &[Vec<char>],
+    track: &[((i32, i32), Direction)],
+    index: usize,
+) -> bool {
+    let new_grid = get_new_grid(grid, track[index].0);
+
+    matches!(walk(&new_grid, track[index - 1].0, track[index].1).1, Outcome::Loops { .. })
+}
+
+fn get_new_grid(grid: &[Vec<char>], location_for_block: (i32, i32)) -> Vec<Vec<char>> {
+    let mut new_grid = grid.to_vec();
+    new_grid[location_for_block.1 as usize][location_for_block.0 as usize] = '#';
+
+    new_grid
+}
+
+#[test]
+fn small_input() {
+    assert_eq!(41, part_one(EXAMPLE));
+    assert_eq!(6, part_two(EXAMPLE));
+}
+
+#[test]
+fn walks_end_by_leaving_or_looping() {
+    let mut grid = parse_input(EXAMPLE);
+    let starting_point = find_start(&grid);
+
+    assert_eq!(Outcome::Exits { steps: 45 }, get_track(&grid, &starting_point).1);
+
+    grid[6][3] = '#';
+    let (track, outcome) = get_track(&grid, &starting_point);
+    assert_eq!(Outcome::Loops { cycle_start: 1, cycle_length: 18 }, outcome);
+    assert_eq!(((4, 5), North), track[1]);
+    assert_eq!(19, track.len());
+
+    let corner = parse_input(".#.\n#^#\n...");
+    assert_eq!(Outcome::Exits { steps: 2 }, get_track(&corner, &find_start(&corner)).1);
+}
+
+#[test]
+fn walled_in_guards_loop_on_the_spot() {
+    let walled_in = parse_input(".#.\n#^#\n.#.");
+    let (track, outcome) = get_track(&walled_in, &find_start(&walled_in));
+
+    assert_eq!(vec!(((1, 1), North)), track);
+    assert_eq!(Outcome::Loops { cycle_start: 0, cycle_length: 1 }, outcome);
+    assert_eq!(1, part_one(".#.\n#^#\n.#."));
+
+    assert_eq!(1, part_two("...\n#^#\n.#."));
+    assert_eq!(1, part_two_brute_force("...\n#^#\n.#."));
+
+    let boxed_in = parse_input("...\n#^#\n.#.");
+    let (track, _) = get_track(&boxed_in, &find_start(&boxed_in));
+    let obstructions = find_obstructions(&boxed_in, &track);
+    assert_eq!(vec!((1, 0)), obstructions.iter().map(|obstruction| obstruction.position).collect::<Vec<(i32, i32)>>());
+}
@@ -0,0 +1,106 @@
+use crate::jump::Obstacles;
+use crate::{first_visits, get_new_grid, walk, Direction, Outcome, Track};
+use rayon::prelude::*;
+use std::collections::{HashMap, HashSet};
+
+const ROUTE: &str = "\x1b[33m";
+const LOOP: &str = "\x1b[32m";
+const OBSTRUCTION: &str = "\x1b[1;31m";
+const RESET: &str = "\x1b[0m";
+
+/// A square where one more obstacle sends the guard round in circles.
+#[derive(PartialEq, Debug)]
+pub struct Obstruction {
+    pub position: (i32, i32),
+    /// Index into the original track of the step where the guard first reaches the square.
+    pub first_step: usize,
+    /// The steps the guard repeats for ever once it's there.
+    pub loop_path: Track,
+}
+
+/// Indexes into `track` of the steps where an obstruction would make the guard loop.
+pub fn looping_steps(grid: &[Vec<char>], track: &Track) -> Vec<usize> {
+    let obstacles = Obstacles::new(grid);
+
+    first_visits(track)
+        .into_par_iter()
+        .filter(|&index| obstacles.loops(track[index - 1].0, track[index].1, Some(track[index].0)))
+        .collect()
+}
+
+/// Every obstruction that makes the guard loop, in the order the guard reaches them.
+pub fn find_obstructions(grid: &[Vec<char>], track: &Track) -> Vec<Obstruction> {
+    looping_steps(grid, track)
+        .into_par_iter()
+        .map(|index| {
+            let (steps, outcome) = walk(&get_new_grid(grid, track[index].0), track[index - 1].0, track[index].1);
+            let Outcome::Loops { cycle_start, .. } = outcome else {
+                panic!("Obstruction at {:?} doesn't make a loop", track[index].0);
+            };
+
+            Obstruction { position: track[index].0, first_step: index, loop_path: steps[cycle_start..].to_vec() }
+        })
+        .collect()
+}
+
+/// The map with the original route marked `X`, the loop drawn with `|`, `-` and `+` and the
+/// obstruction as `O`, like the puzzle's diagrams, optionally in colour.
+pub fn render(grid: &[Vec<char>], route: &Track, obstruction: &Obstruction, colour: bool) -> String {
+    let mut loop_directions: HashMap<(i32, i32), Vec<Direction>> = HashMap::new();
+    let path = &obstruction.loop_path;
+    for (index, (location, direction)) in path.iter().enumerate() {
+        let leaving = path[(index + 1) % path.len()].1;
+        loop_directions.entry(*location).or_default().extend([*direction, leaving]);
+    }
+    let on_route = route.iter().map(|(location, _)| *location).collect::<HashSet<(i32, i32)>>();
+    let paint = |text: char, code: &str| if colour {format!("{}{}{}", code, text, RESET)} else {text.to_string()};
+
+    let mut rendered = String::new();
+    for (y, line) in grid.iter().enumerate() {
+        for (x, ch) in line.iter().enumerate() {
+            let location = (x as i32, y as i32);
+            let cell = if location == obstruction.position {
+                paint('O', OBSTRUCTION)
+            } else if *ch != '.' {
+                ch.to_string()
+            } else if let Some(directions) = loop_directions.get(&location) {
+                let vertical = directions.iter().any(|direction| direction.get_vector().0 == 0);
+                let horizontal = directions.iter().any(|direction| direction.get_vector().1 == 0);
+                paint(if vertical && horizontal {'+'} else if vertical {'|'} else {'-'}, LOOP)
+            } else if on_route.contains(&location) {
+                paint('X', ROUTE)
+            } else {
+                ch.to_string()
+            };
+            rendered.push_str(&cell);
+        }
+        rendered.push('\n');
+    }
+
+    rendered
+}
+
+#[test]
+fn obstructions_come_with_their_loops() {
+    let grid = crate::parse_input(crate::EXAMPLE);
+    let (track, _) = crate::get_track(&grid, &crate::find_start(&grid));
+    let obstructions = find_obstructions(&grid, &track);
+
+    assert_eq!(
+        vec!((3, 6), (6, 7), (3, 8), (1, 8), (7, 7), (7, 9)),
+        obstructions.iter().map(|obstruction| obstruction.position).collect::<Vec<(i32, i32)>>()
+    );
+    assert!(obstructions.windows(2).all(|pair| pair[0].first_step < pair[1].first_step));
+    assert_eq!(18, obstructions[0].loop_path.len());
+    assert_eq!("....#.....
+....+---+#
+....|...|.
+..#.|...|.
+..XX|XX#|.
+..X.|.X.|.
+.#XO^---+.
+.XXXXXXX#.
+#XXXXXXX..
+......#X..
+", render(&grid, &track, &obstructions[0], false));
+}
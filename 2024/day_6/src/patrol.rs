@@ -0,0 +1,195 @@
+use crate::Direction::{East, North, South, West};
+use crate::{Direction, Outcome, Track, Visited};
+use std::collections::HashSet;
+
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub enum TurnPolicy {
+    Right,
+    Left,
+    Reverse,
+}
+
+/// What happens when a guard walks into the side of the map.
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub enum Edge {
+    Exit,
+    Wall,
+    Wraparound,
+}
+
+#[derive(PartialEq, Debug)]
+pub struct Guard {
+    pub start: (i32, i32),
+    pub direction: Direction,
+    pub policy: TurnPolicy,
+}
+
+/// How each guard's walk ends and how many squares the guards cover between them.
+pub struct Patrol {
+    pub outcomes: Vec<Outcome>,
+    pub covered: usize,
+}
+
+impl TurnPolicy {
+    pub fn parse(name: &str) -> Option<TurnPolicy> {
+        match name {
+            "right" => Some(TurnPolicy::Right),
+            "left" => Some(TurnPolicy::Left),
+            "reverse" => Some(TurnPolicy::Reverse),
+            _ => None,
+        }
+    }
+
+    fn turn(self, direction: Direction) -> Direction {
+        match self {
+            TurnPolicy::Right => direction.next(),
+            TurnPolicy::Left => direction.next().next().next(),
+            TurnPolicy::Reverse => direction.next().next(),
+        }
+    }
+}
+
+impl Edge {
+    pub fn parse(name: &str) -> Option<Edge> {
+        match name {
+            "exit" => Some(Edge::Exit),
+            "wall" => Some(Edge::Wall),
+            "wrap" => Some(Edge::Wraparound),
+            _ => None,
+        }
+    }
+}
+
+impl Patrol {
+    /// Whether there are guards and every one ends up going round in circles, so the patrol
+    /// never ends.
+    pub fn loops(&self) -> bool {
+        !self.outcomes.is_empty() && self.outcomes.iter().all(|outcome| matches!(outcome, Outcome::Loops { .. }))
+    }
+}
+
+/// Every guard on the map in reading order, each marker showing which way they face, with
+/// `policies` handed out in the same order and anyone left over turning right.
+pub fn find_guards(grid: &[Vec<char>], policies: &[TurnPolicy]) -> Vec<Guard> {
+    let markers = grid.iter().enumerate().flat_map(|(y, line)| {
+        line.iter().enumerate().filter_map(move |(x, ch)| {
+            let direction = match ch {
+                '^' => North,
+                '>' => East,
+                'v' => South,
+                '<' => West,
+                _ => return None,
+            };
+            Some(((x as i32, y as i32), direction))
+        })
+    });
+
+    markers
+        .enumerate()
+        .map(|(index, (start, direction))| Guard {
+            start,
+            direction,
+            policy: policies.get(index).copied().unwrap_or(TurnPolicy::Right),
+        })
+        .collect()
+}
+
+/// Walks each guard until they leave the map or start repeating themselves. The map has to
+/// be rectangular so that wrapping round an edge lands back on it.
+pub fn patrol(grid: &[Vec<char>], guards: &[Guard], edge: Edge) -> Result<Patrol, String> {
+    if grid.iter().any(|line| line.len() != grid[0].len()) {
+        return Err(String::from("Every row of the map needs the same width"));
+    }
+
+    let mut covered = HashSet::new();
+    let mut outcomes = vec![];
+
+    for guard in guards {
+        let (track, outcome) = walk_guard(grid, guard, edge);
+        covered.extend(track.iter().map(|(location, _)| *location));
+        outcomes.push(outcome);
+    }
+
+    Ok(Patrol { outcomes, covered: covered.len() })
+}
+
+fn walk_guard(grid: &[Vec<char>], guard: &Guard, edge: Edge) -> (Track, Outcome) {
+    let (width, height) = (grid[0].len() as i32, grid.len() as i32);
+    let on_map = |(x, y): (i32, i32)| (0..width).contains(&x) && (0..height).contains(&y);
+    let blocked = |location: (i32, i32)| {
+        if on_map(location) {grid[location.1 as usize][location.0 as usize] == '#'} else {edge == Edge::Wall}
+    };
+    let ahead = |location: (i32, i32), direction: Direction| {
+        let (x, y) = (location.0 + direction.get_vector().0, location.1 + direction.get_vector().1);
+        if edge == Edge::Wraparound {(x.rem_euclid(width), y.rem_euclid(height))} else {(x, y)}
+    };
+
+    let mut track = vec![];
+    let mut visited = Visited::new(width as usize, height as usize);
+    let (mut location, mut direction) = (guard.start, guard.direction);
+
+    while on_map(location) {
+        if !visited.insert(location, direction) {
+            let cycle_start = track.iter().position(|&step| step == (location, direction)).unwrap();
+            let cycle_length = track.len() - cycle_start;
+            return (track, Outcome::Loops { cycle_start, cycle_length });
+        }
+        track.push((location, direction));
+
+        for _ in 0..4 {
+            if !blocked(ahead(location, direction)) {
+                break;
+            }
+            direction = guard.policy.turn(direction);
+        }
+        if !blocked(ahead(location, direction)) {
+            location = ahead(location, direction);
+        }
+    }
+
+    let steps = track.len();
+    (track, Outcome::Exits { steps })
+}
+
+#[test]
+fn guards_patrol_together() {
+    let grid = crate::parse_input(crate::EXAMPLE);
+    let alone = patrol(&grid, &find_guards(&grid, &[]), Edge::Exit).unwrap();
+    assert_eq!(crate::part_one(crate::EXAMPLE), alone.covered);
+    assert_eq!(vec!(Outcome::Exits { steps: 45 }), alone.outcomes);
+
+    let grid = crate::parse_input("#...#
+..>..
+.....
+<...#");
+    let guards = find_guards(&grid, &[TurnPolicy::Left]);
+    assert_eq!(
+        vec!(
+            Guard { start: (2, 1), direction: East, policy: TurnPolicy::Left },
+            Guard { start: (0, 3), direction: West, policy: TurnPolicy::Right },
+        ),
+        guards
+    );
+    let exits = patrol(&grid, &guards, Edge::Exit).unwrap();
+    assert_eq!(vec!(Outcome::Exits { steps: 3 }, Outcome::Exits { steps: 1 }), exits.outcomes);
+    assert_eq!(4, exits.covered);
+    assert!(!exits.loops());
+
+    let walled = patrol(&grid, &guards, Edge::Wall).unwrap();
+    assert!(walled.loops());
+
+    let wrapped = patrol(&grid, &[Guard { start: (0, 2), direction: East, policy: TurnPolicy::Reverse }], Edge::Wraparound).unwrap();
+    assert_eq!(vec!(Outcome::Loops { cycle_start: 0, cycle_length: 5 }), wrapped.outcomes);
+    assert_eq!(5, wrapped.covered);
+}
+
+#[test]
+fn empty_and_ragged_maps() {
+    let empty = crate::parse_input("...\n...");
+    let nobody = patrol(&empty, &find_guards(&empty, &[]), Edge::Wall).unwrap();
+    assert_eq!(0, nobody.covered);
+    assert!(!nobody.loops());
+
+    let ragged = crate::parse_input("....\n.>\n...");
+    assert!(patrol(&ragged, &find_guards(&ragged, &[]), Edge::Wraparound).is_err());
+}
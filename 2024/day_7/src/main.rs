@@ -1,5 +1,4 @@
-use std::fs;
-use std::time::Instant;
+use aoc::{Answer, Solution};
 use crate::Operation::{Add, Multiply, Concat};
 
 #[derive(Clone, Debug)]
@@ -9,14 +8,23 @@ enum Operation {
     Concat
 }
 
-fn main() {
-    let input = fs::read_to_string("input.txt").expect("Could not read file");
+struct Day7;
+
+impl Solution for Day7 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 7;
 
-    let start = Instant::now();
-    println!("Part One: {} - {:?}", part_one(&input), start.elapsed());
+    fn part_one(input: &str) -> impl Into<Answer> {
+        part_one(input)
+    }
 
-    let start = Instant::now();
-    println!("Part Two: {} - {:?}", part_two(&input), start.elapsed());
+    fn part_two(input: &str) -> Option<impl Into<Answer>> {
+        Some(part_two(input))
+    }
+}
+
+fn main() {
+    aoc::run::<Day7>();
 }
 
 fn part_one(input: &str) -> u64 {
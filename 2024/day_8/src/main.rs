@@ -1,16 +1,24 @@
+use aoc::{Answer, Solution};
 use itertools::Itertools;
 use std::cmp::PartialEq;
-use std::fs;
-use std::time::Instant;
 
-fn main() {
-    let input = fs::read_to_string("input.txt").expect("Could not read file");
+struct Day8;
+
+impl Solution for Day8 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 8;
 
-    let start = Instant::now();
-    println!("Part One: {} - {:?}", part_one(&input), start.elapsed());
+    fn part_one(input: &str) -> impl Into<Answer> {
+        part_one(input)
+    }
 
-    let start = Instant::now();
-    println!("Part Two: {} - {:?}", part_two(&input), start.elapsed());
+    fn part_two(input: &str) -> Option<impl Into<Answer>> {
+        Some(part_two(input))
+    }
+}
+
+fn main() {
+    aoc::run::<Day8>();
 }
 
 #[derive(Debug, PartialEq)]
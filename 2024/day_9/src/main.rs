@@ -1,5 +1,6 @@
-use std::fs;
-use std::time::Instant;
+use aoc::{Answer, Implementation, Solution};
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
 
 #[derive(Debug)]
 #[derive(Clone)]
@@ -10,14 +11,31 @@ struct DataBlock {
     moved: bool
 }
 
-fn main() {
-    let input = fs::read_to_string("input.txt").expect("Could not read file");
+struct Day9;
+
+impl Solution for Day9 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 9;
+
+    fn part_one(input: &str) -> impl Into<Answer> {
+        part_one(input)
+    }
 
-    let start = Instant::now();
-    println!("Part One: {} - {:?}", part_one(&input), start.elapsed());
+    fn part_two(input: &str) -> Option<impl Into<Answer>> {
+        Some(part_two(input))
+    }
 
-    let start = Instant::now();
-    println!("Part Two: {} - {:?}", part_two(&input), start.elapsed());
+    fn alternatives() -> Vec<Implementation> {
+        vec![Implementation {
+            name: "heap",
+            part: 2,
+            solve: |input| part_two_heap(input).into(),
+        }]
+    }
+}
+
+fn main() {
+    aoc::run::<Day9>();
 }
 
 fn part_one(input: &str) -> u64 {
@@ -50,6 +68,55 @@ fn part_two(input: &str) -> u64 {
       .sum()
 }
 
+/// Keeps a min-heap of free span start positions for each span length, so each file finds
+/// the leftmost span it fits in by checking at most nine heaps.
+fn part_two_heap(input: &str) -> u64 {
+    let mut files = vec![];
+    let mut free_spans: Vec<BinaryHeap<Reverse<usize>>> = vec![BinaryHeap::new(); 10];
+    let mut position = 0;
+
+    for (index, instruction) in input.trim().chars().enumerate() {
+        let quantity = instruction.to_digit(10).unwrap() as usize;
+        if index % 2 == 0 {
+            files.push((position, quantity));
+        } else if quantity != 0 {
+            free_spans[quantity].push(Reverse(position));
+        }
+        position += quantity;
+    }
+
+    files
+        .iter()
+        .enumerate()
+        .rev()
+        .map(|(item, &(start_index, quantity))| {
+            let leftmost_span = (quantity..free_spans.len())
+                .filter_map(|span_length| {
+                    free_spans[span_length]
+                        .peek()
+                        .map(|Reverse(span_start)| (*span_start, span_length))
+                })
+                .filter(|(span_start, _)| *span_start < start_index)
+                .min();
+
+            let new_start_index = match leftmost_span {
+                Some((span_start, span_length)) => {
+                    free_spans[span_length].pop();
+                    if span_length > quantity {
+                        free_spans[span_length - quantity].push(Reverse(span_start + quantity));
+                    }
+                    span_start
+                }
+                None => start_index,
+            };
+
+            (new_start_index..new_start_index + quantity)
+                .map(|index| index as u64 * item as u64)
+                .sum::<u64>()
+        })
+        .sum()
+}
+
 fn build_disk(instructions: Vec<char>) -> Vec<Option<u64>> {
     let mut disk: Vec<Option<u64>> = Vec::new();
     let mut index = 0;
@@ -172,7 +239,7 @@ fn compress_blocks(disk: Vec<DataBlock>) -> Vec<DataBlock> {
     compressed_disk
 }
 
-fn find_spot_for_block(disk: &Vec<DataBlock>, item_index: usize) -> Option<usize> {
+fn find_spot_for_block(disk: &[DataBlock], item_index: usize) -> Option<usize> {
     let mut forward_index = 0;
     let item_to_move = &disk[item_index];
     
@@ -192,4 +259,5 @@ fn small_input() {
     let input = "2333133121414131402";
     assert_eq!(1928, part_one(input));
     assert_eq!(2858, part_two(input));
+    assert_eq!(2858, part_two_heap(input));
 }
@@ -0,0 +1,83 @@
+use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter};
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "type", content = "value", rename_all = "lowercase")]
+pub enum Answer {
+    Integer(i128),
+    String(String),
+    Coordinate(i64, i64),
+    /// A part that gave up with this message, which the runner records as an error.
+    #[serde(skip)]
+    Failed(String),
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Answer::Integer(value) => write!(f, "{}", value),
+            Answer::String(value) => write!(f, "{}", value),
+            Answer::Coordinate(x, y) => write!(f, "{},{}", x, y),
+            Answer::Failed(message) => write!(f, "failed - {}", message),
+        }
+    }
+}
+
+macro_rules! integer_answer {
+    ($($integer:ty),*) => {
+        $(
+            impl From<$integer> for Answer {
+                fn from(value: $integer) -> Self {
+                    Answer::Integer(value as i128)
+                }
+            }
+        )*
+    };
+}
+
+integer_answer!(i32, i64, i128, u32, u64, usize);
+
+impl From<String> for Answer {
+    fn from(value: String) -> Self {
+        Answer::String(value)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(value: &str) -> Self {
+        Answer::String(value.to_string())
+    }
+}
+
+impl From<(usize, usize)> for Answer {
+    fn from((x, y): (usize, usize)) -> Self {
+        Answer::Coordinate(x as i64, y as i64)
+    }
+}
+
+impl<T: Into<Answer>, E: Display> From<Result<T, E>> for Answer {
+    fn from(result: Result<T, E>) -> Self {
+        result.map_or_else(|error| Answer::Failed(error.to_string()), Into::into)
+    }
+}
+
+#[test]
+fn answers_display_like_the_puzzle_expects() {
+    assert_eq!("240", Answer::from(240usize).to_string());
+    assert_eq!("7,3,0,5", Answer::from("7,3,0,5").to_string());
+    assert_eq!("26,50", Answer::from((26usize, 50usize)).to_string());
+    assert_eq!(Answer::Integer(4), Answer::from(Ok::<u32, String>(4)));
+    assert_eq!(Answer::Failed(String::from("no")), Answer::from(Err::<u32, &str>("no")));
+}
+
+#[test]
+fn answers_serialise_with_their_type() {
+    assert_eq!(
+        r#"{"type":"integer","value":333027885676693}"#,
+        serde_json::to_string(&Answer::from(333027885676693u64)).unwrap()
+    );
+    assert_eq!(
+        r#"{"type":"coordinate","value":[26,50]}"#,
+        serde_json::to_string(&Answer::from((26usize, 50usize))).unwrap()
+    );
+}
@@ -0,0 +1,101 @@
+use crate::Answer;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::env;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::UNIX_EPOCH;
+
+const FNV_OFFSET_BASIS: u64 = 0xcbf29ce484222325;
+const FNV_PRIME: u64 = 0x100000001b3;
+
+#[derive(Serialize, Deserialize)]
+struct Entry {
+    version: String,
+    answer: Answer,
+}
+
+/// Answers from previous runs, keyed by year, day, part and input hash.
+pub struct Cache {
+    path: PathBuf,
+    entries: BTreeMap<String, Entry>,
+}
+
+impl Cache {
+    /// Loads the cache at `path`, starting empty if it is missing or unreadable.
+    pub fn load(path: &Path) -> Cache {
+        let entries = fs::read_to_string(path)
+            .ok()
+            .and_then(|contents| serde_json::from_str(&contents).ok())
+            .unwrap_or_default();
+
+        Cache { path: path.to_path_buf(), entries }
+    }
+
+    /// Returns the cached answer, unless it was produced by a different build of the solver.
+    pub fn get(&self, key: &str, version: &str) -> Option<&Answer> {
+        self.entries
+            .get(key)
+            .filter(|entry| entry.version == version)
+            .map(|entry| &entry.answer)
+    }
+
+    pub fn insert(&mut self, key: String, version: &str, answer: Answer) {
+        self.entries.insert(key, Entry { version: version.to_string(), answer });
+    }
+
+    pub fn save(&self) -> io::Result<()> {
+        let contents = serde_json::to_string_pretty(&self.entries).map_err(io::Error::other)?;
+        fs::write(&self.path, contents)
+    }
+}
+
+pub fn key(year: u16, day: u8, part: u8, implementation: &str, input: &str) -> String {
+    format!("{}/{}/{}/{}/{:016x}", year, day, part, implementation, input_hash(input))
+}
+
+/// Identifies the build of the running solver, so rebuilding after a code change misses the cache.
+pub fn solver_version() -> String {
+    env::current_exe()
+        .and_then(fs::metadata)
+        .and_then(|metadata| {
+            let modified = metadata
+                .modified()?
+                .duration_since(UNIX_EPOCH)
+                .map_err(io::Error::other)?;
+            Ok(format!("{}-{}", metadata.len(), modified.as_nanos()))
+        })
+        .unwrap_or_default()
+}
+
+/// Hashes the input with line endings and trailing whitespace normalised away.
+fn input_hash(input: &str) -> u64 {
+    input
+        .trim_end()
+        .lines()
+        .flat_map(|line| line.trim_end().bytes().chain([b'\n']))
+        .fold(FNV_OFFSET_BASIS, |hash, byte| (hash ^ byte as u64).wrapping_mul(FNV_PRIME))
+}
+
+#[test]
+fn input_hash_ignores_line_endings_and_trailing_whitespace() {
+    assert_eq!(input_hash("3   4\n4   3\n"), input_hash("3   4  \r\n4   3\r\n\r\n"));
+    assert_ne!(input_hash("3   4\n4   3"), input_hash("3   4\n4   4"));
+}
+
+#[test]
+fn entries_from_another_build_are_ignored() {
+    let path = env::temp_dir().join(format!("aoc_cache_{}.json", std::process::id()));
+    let key = key(2024, 18, 2, "default", "1,2");
+
+    let mut cache = Cache::load(&path);
+    cache.insert(key.clone(), "build-1", Answer::Coordinate(26, 50));
+    cache.save().unwrap();
+
+    let cache = Cache::load(&path);
+    assert_eq!(Some(&Answer::Coordinate(26, 50)), cache.get(&key, "build-1"));
+    assert_eq!(None, cache.get(&key, "build-2"));
+
+    fs::remove_file(path).unwrap();
+}
@@ -0,0 +1,129 @@
+use crate::{solve_part, Format, Record, Solution, DEFAULT_IMPLEMENTATION};
+
+/// Runs every implementation of each part on the same input and reports their answers and timings.
+/// Returns whether each part's implementations all agreed.
+pub fn compare<S: Solution>(input: &str, format: Format) -> bool {
+    let alternatives = S::alternatives();
+    let mut all_agree = true;
+
+    for part in [1, 2] {
+        let mut records = vec![solve_part::<S>(part, DEFAULT_IMPLEMENTATION, input)];
+        records.extend(
+            alternatives
+                .iter()
+                .filter(|alternative| alternative.part == part)
+                .map(|alternative| solve_part::<S>(part, alternative.name, input)),
+        );
+        records.retain(|record| !record.is_empty());
+
+        if records.is_empty() {
+            continue;
+        }
+
+        let agree = implementations_agree(&records);
+        all_agree &= agree;
+
+        match format {
+            Format::Json => records.iter().for_each(|record| record.print(format)),
+            Format::Text => print_table(part, &records, agree),
+        }
+    }
+
+    all_agree
+}
+
+fn implementations_agree(records: &[Record]) -> bool {
+    records
+        .iter()
+        .all(|record| record.error.is_none() && record.answer == records[0].answer)
+}
+
+fn print_table(part: u8, records: &[Record], agree: bool) {
+    let fastest = records
+        .iter()
+        .filter(|record| record.error.is_none())
+        .map(|record| record.duration)
+        .min()
+        .unwrap_or_default();
+
+    let rows: Vec<[String; 4]> = records
+        .iter()
+        .map(|record| {
+            let answer = match (&record.answer, &record.error) {
+                (Some(answer), _) => answer.to_string(),
+                (None, error) => format!("failed - {}", error.as_deref().unwrap_or("no answer")),
+            };
+            let relative = if record.error.is_none() && !fastest.is_zero() {
+                format!("{:.2}x", record.duration.as_secs_f64() / fastest.as_secs_f64())
+            } else {
+                String::from("-")
+            };
+
+            [record.implementation.clone(), answer, format!("{:?}", record.duration), relative]
+        })
+        .collect();
+
+    let header = [
+        String::from("Implementation"),
+        String::from("Answer"),
+        String::from("Time"),
+        String::from("Relative"),
+    ];
+    let widths: Vec<usize> = (0..header.len())
+        .map(|column| {
+            rows.iter()
+                .chain([&header])
+                .map(|row| row[column].chars().count())
+                .max()
+                .unwrap_or_default()
+        })
+        .collect();
+
+    println!("Part {}", if part == 1 { "One" } else { "Two" });
+    for row in [&header].into_iter().chain(rows.iter()) {
+        println!(
+            "  {:<name$}  {:<answer$}  {:>time$}  {:>relative$}",
+            row[0],
+            row[1],
+            row[2],
+            row[3],
+            name = widths[0],
+            answer = widths[1],
+            time = widths[2],
+            relative = widths[3],
+        );
+    }
+
+    if records.len() == 1 {
+        println!("  Only one implementation");
+    } else if agree {
+        println!("  All {} implementations agree", records.len());
+    } else {
+        println!("  Implementations disagree");
+    }
+}
+
+#[test]
+fn disagreeing_or_failing_implementations_are_reported() {
+    use crate::Answer;
+    use std::time::Duration;
+
+    let record = |implementation: &str, answer: Option<Answer>, error: Option<&str>| Record {
+        year: 2024,
+        day: 1,
+        part: 1,
+        implementation: implementation.to_string(),
+        answer,
+        duration: Duration::ZERO,
+        cached: false,
+        error: error.map(String::from),
+    };
+
+    let matching = [record("default", Some(Answer::Integer(3)), None), record("fast", Some(Answer::Integer(3)), None)];
+    let different = [record("default", Some(Answer::Integer(3)), None), record("fast", Some(Answer::Integer(4)), None)];
+    let failing = [record("default", Some(Answer::Integer(3)), None), record("fast", None, Some("panicked"))];
+
+    assert!(implementations_agree(&matching));
+    assert!(!implementations_agree(&different));
+    assert!(!implementations_agree(&failing));
+}
@@ -0,0 +1,391 @@
+mod answer;
+mod cache;
+mod compare;
+mod random;
+
+pub use answer::Answer;
+pub use random::Lcg;
+
+use cache::Cache;
+use clap::{Parser, Subcommand, ValueEnum};
+use serde::{Serialize, Serializer};
+use std::fs;
+use std::panic::{self, AssertUnwindSafe};
+use std::path::Path;
+use std::process;
+use std::time::{Duration, Instant};
+
+const CACHE_FILE: &str = ".answer_cache.json";
+
+/// The name `--impl` uses for a day's `part_one`/`part_two`.
+pub const DEFAULT_IMPLEMENTATION: &str = "default";
+
+pub trait Solution {
+    const YEAR: u16;
+    const DAY: u8;
+
+    fn part_one(input: &str) -> impl Into<Answer>;
+
+    fn part_two(_input: &str) -> Option<impl Into<Answer>> {
+        None::<Answer>
+    }
+
+    /// Other ways of solving a part, kept alongside the default for `--impl` and `compare`.
+    fn alternatives() -> Vec<Implementation> {
+        Vec::new()
+    }
+
+    /// Extra day-specific modes, run by name on the command line instead of printing the answers.
+    fn commands() -> Vec<Command> {
+        Vec::new()
+    }
+}
+
+pub struct Implementation {
+    pub name: &'static str,
+    pub part: u8,
+    pub solve: fn(&str) -> Answer,
+}
+
+pub struct Command {
+    pub name: &'static str,
+    pub about: &'static str,
+    /// Called with the puzzle input and any arguments following the command's name.
+    pub run: fn(&str, &[String]) -> Result<(), String>,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum Format {
+    Text,
+    Json,
+}
+
+#[derive(Parser)]
+struct RunArgs {
+    /// How answers are written to stdout
+    #[arg(long, value_enum, default_value_t = Format::Text)]
+    format: Format,
+    /// Recompute answers even when a cached answer exists for this input
+    #[arg(long)]
+    no_cache: bool,
+    /// Which registered implementation to run for each part that has one
+    #[arg(long = "impl", default_value = DEFAULT_IMPLEMENTATION)]
+    implementation: String,
+    #[command(subcommand)]
+    mode: Option<Mode>,
+}
+
+#[derive(Subcommand)]
+enum Mode {
+    /// Run every implementation of each part on the same input, check they agree and compare timings
+    Compare,
+    #[command(external_subcommand)]
+    Day(Vec<String>),
+}
+
+#[derive(Debug, Serialize)]
+pub struct Record {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    pub implementation: String,
+    pub answer: Option<Answer>,
+    #[serde(rename = "duration_ns", serialize_with = "serialize_nanos")]
+    pub duration: Duration,
+    pub cached: bool,
+    pub error: Option<String>,
+}
+
+impl Record {
+    fn new<S: Solution>(part: u8, implementation: &str) -> Record {
+        Record {
+            year: S::YEAR,
+            day: S::DAY,
+            part,
+            implementation: implementation.to_string(),
+            answer: None,
+            duration: Duration::ZERO,
+            cached: false,
+            error: None,
+        }
+    }
+
+    fn is_empty(&self) -> bool {
+        self.answer.is_none() && self.error.is_none()
+    }
+
+    fn print(&self, format: Format) {
+        match format {
+            Format::Json => println!("{}", serde_json::to_string(self).expect("Could not serialise record")),
+            Format::Text => {
+                let mut part = String::from(if self.part == 1 { "One" } else { "Two" });
+                if self.implementation != DEFAULT_IMPLEMENTATION {
+                    part = format!("{} ({})", part, self.implementation);
+                }
+                match (&self.answer, &self.error) {
+                    (Some(answer), _) if self.cached => println!("Part {}: {} - cached", part, answer),
+                    (Some(answer), _) => println!("Part {}: {} - {:?}", part, answer, self.duration),
+                    (None, Some(error)) => println!("Part {}: failed - {}", part, error),
+                    (None, None) => {}
+                }
+            }
+        }
+    }
+}
+
+fn serialize_nanos<S: Serializer>(duration: &Duration, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.serialize_u128(duration.as_nanos())
+}
+
+pub fn run<S: Solution>() {
+    let args = RunArgs::parse();
+
+    let input = match fs::read_to_string("input.txt") {
+        Ok(input) => input,
+        Err(error) => {
+            let mut record = Record::new::<S>(1, &args.implementation);
+            record.error = Some(format!("Could not read input.txt: {}", error));
+            record.print(args.format);
+            return;
+        }
+    };
+
+    match args.mode {
+        Some(Mode::Compare) => {
+            if !compare::compare::<S>(&input, args.format) {
+                process::exit(1);
+            }
+        }
+        Some(Mode::Day(command)) => {
+            if let Err(error) = run_command::<S>(&input, &command) {
+                eprintln!("{}", error);
+                process::exit(1);
+            }
+        }
+        None => {
+            if let Err(error) = check_implementation::<S>(&args.implementation) {
+                eprintln!("{}", error);
+                process::exit(1);
+            }
+            answer::<S>(&input, &args)
+        }
+    }
+}
+
+fn check_implementation<S: Solution>(name: &str) -> Result<(), String> {
+    let alternatives = S::alternatives();
+    if name == DEFAULT_IMPLEMENTATION || alternatives.iter().any(|alternative| alternative.name == name) {
+        return Ok(());
+    }
+
+    let mut names = vec![DEFAULT_IMPLEMENTATION];
+    alternatives.iter().for_each(|alternative| {
+        if !names.contains(&alternative.name) {
+            names.push(alternative.name);
+        }
+    });
+    Err(format!("Unknown implementation {}, day {} has: {}", name, S::DAY, names.join(", ")))
+}
+
+fn run_command<S: Solution>(input: &str, command: &[String]) -> Result<(), String> {
+    let commands = S::commands();
+    let name = command.first().map(String::as_str).unwrap_or_default();
+
+    match commands.iter().find(|candidate| candidate.name == name) {
+        Some(found) => (found.run)(input, &command[1..]),
+        None => Err(format!(
+            "Unknown command {}, day {} has:{}",
+            name,
+            S::DAY,
+            commands
+                .iter()
+                .map(|command| format!("\n  {:<12}{}", command.name, command.about))
+                .collect::<String>()
+        )),
+    }
+}
+
+fn answer<S: Solution>(input: &str, args: &RunArgs) {
+    let alternatives = S::alternatives();
+    let mut cache = Cache::load(Path::new(CACHE_FILE));
+    let version = cache::solver_version();
+    let mut cache_changed = false;
+
+    for part in [1, 2] {
+        let implementation = implementation_for_part(&alternatives, part, &args.implementation);
+        let key = cache::key(S::YEAR, S::DAY, part, implementation, input);
+
+        let record = match cache.get(&key, &version).filter(|_| !args.no_cache) {
+            Some(answer) => {
+                let mut record = Record::new::<S>(part, implementation);
+                record.answer = Some(answer.clone());
+                record.cached = true;
+                record
+            }
+            None => {
+                let record = solve_part::<S>(part, implementation, input);
+                if let Some(answer) = &record.answer {
+                    cache.insert(key, &version, answer.clone());
+                    cache_changed = true;
+                }
+                record
+            }
+        };
+
+        if !record.is_empty() {
+            record.print(args.format);
+        }
+    }
+
+    if cache_changed {
+        if let Err(error) = cache.save() {
+            eprintln!("Could not save {}: {}", CACHE_FILE, error);
+        }
+    }
+}
+
+/// Solves both parts, turning a panicking part into a record carrying the panic message.
+pub fn solve<S: Solution>(input: &str) -> Vec<Record> {
+    [1, 2]
+        .into_iter()
+        .map(|part| solve_part::<S>(part, DEFAULT_IMPLEMENTATION, input))
+        .filter(|record| !record.is_empty())
+        .collect()
+}
+
+/// Falls back to the default for parts that have no implementation with the requested name.
+fn implementation_for_part<'a>(alternatives: &[Implementation], part: u8, name: &'a str) -> &'a str {
+    if alternatives.iter().any(|alternative| alternative.part == part && alternative.name == name) {
+        name
+    } else {
+        DEFAULT_IMPLEMENTATION
+    }
+}
+
+fn solve_part<S: Solution>(part: u8, implementation: &str, input: &str) -> Record {
+    let alternative = S::alternatives()
+        .into_iter()
+        .find(|alternative| alternative.part == part && alternative.name == implementation);
+
+    match alternative {
+        Some(alternative) => time_part::<S>(part, alternative.name, || Some((alternative.solve)(input))),
+        None if part == 1 => time_part::<S>(part, DEFAULT_IMPLEMENTATION, || Some(S::part_one(input).into())),
+        None => time_part::<S>(part, DEFAULT_IMPLEMENTATION, || S::part_two(input).map(Into::into)),
+    }
+}
+
+fn time_part<S: Solution>(part: u8, implementation: &str, solve: impl FnOnce() -> Option<Answer>) -> Record {
+    let start = Instant::now();
+    let result = panic::catch_unwind(AssertUnwindSafe(solve));
+    let duration = start.elapsed();
+
+    let mut record = Record::new::<S>(part, implementation);
+    record.duration = duration;
+    match result {
+        Ok(Some(Answer::Failed(message))) => record.error = Some(message),
+        Ok(answer) => record.answer = answer,
+        Err(payload) => record.error = Some(panic_message(payload)),
+    }
+
+    record
+}
+
+fn panic_message(payload: Box<dyn std::any::Any + Send>) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message.to_string()
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        String::from("panicked")
+    }
+}
+
+#[cfg(test)]
+struct Example;
+
+#[cfg(test)]
+impl Solution for Example {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 1;
+
+    fn part_one(input: &str) -> impl Into<Answer> {
+        input.len()
+    }
+
+    fn part_two(input: &str) -> Option<impl Into<Answer>> {
+        Some(input.parse::<u32>().expect("not a number"))
+    }
+
+    fn alternatives() -> Vec<Implementation> {
+        vec![
+            Implementation {
+                name: "chars",
+                part: 1,
+                solve: |input| input.chars().count().into(),
+            },
+            Implementation {
+                name: "checked",
+                part: 2,
+                solve: |input| input.parse::<u32>().into(),
+            },
+        ]
+    }
+
+    fn commands() -> Vec<Command> {
+        vec![Command {
+            name: "echo",
+            about: "Fails unless given the input as its argument",
+            run: |input, args| match args {
+                [argument] if argument == input => Ok(()),
+                _ => Err(String::from("wrong arguments")),
+            },
+        }]
+    }
+}
+
+#[test]
+fn panicking_parts_become_error_records() {
+    let records = solve::<Example>("abc");
+
+    assert_eq!(Some(Answer::Integer(3)), records[0].answer);
+    assert_eq!(None, records[1].answer);
+    assert!(records[1].error.as_ref().unwrap().contains("not a number"));
+}
+
+#[test]
+fn failed_answers_become_error_records() {
+    let record = solve_part::<Example>(2, "checked", "abc");
+
+    assert_eq!(None, record.answer);
+    assert_eq!(Some(String::from("invalid digit found in string")), record.error);
+    assert_eq!(Some(Answer::Integer(12)), solve_part::<Example>(2, "checked", "12").answer);
+}
+
+#[test]
+fn alternatives_are_selected_by_name_per_part() {
+    let alternatives = Example::alternatives();
+
+    assert_eq!("chars", implementation_for_part(&alternatives, 1, "chars"));
+    assert_eq!(DEFAULT_IMPLEMENTATION, implementation_for_part(&alternatives, 2, "chars"));
+    assert_eq!(Some(Answer::Integer(3)), solve_part::<Example>(1, "chars", "abc").answer);
+    assert!(compare::compare::<Example>("12", Format::Json));
+}
+
+#[test]
+fn unknown_implementations_list_the_known_ones() {
+    assert_eq!(Ok(()), check_implementation::<Example>("chars"));
+    assert_eq!(Ok(()), check_implementation::<Example>(DEFAULT_IMPLEMENTATION));
+    assert_eq!(
+        Err(String::from("Unknown implementation char, day 1 has: default, chars, checked")),
+        check_implementation::<Example>("char")
+    );
+}
+
+#[test]
+fn commands_are_found_by_name() {
+    let command = |words: &[&str]| words.iter().map(|word| word.to_string()).collect::<Vec<String>>();
+
+    assert_eq!(Ok(()), run_command::<Example>("abc", &command(&["echo", "abc"])));
+    assert!(run_command::<Example>("abc", &command(&["echo"])).is_err());
+    assert!(run_command::<Example>("abc", &command(&["missing"])).unwrap_err().contains("echo"));
+}
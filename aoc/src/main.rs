@@ -0,0 +1,63 @@
+mod runner;
+mod scaffold;
+
+use clap::{Parser, Subcommand};
+use std::path::Path;
+use std::process;
+
+#[derive(Parser)]
+#[command(about = "Advent of Code toolchain")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Create a new day from the standard template
+    New {
+        year: u16,
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+    },
+    /// Run one day, or every day of a year, passing any remaining arguments through
+    Run {
+        year: u16,
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: Option<u8>,
+        #[arg(last = true)]
+        args: Vec<String>,
+    },
+}
+
+fn main() {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR")).parent().expect("Could not find repository root");
+
+    match Cli::parse().command {
+        Command::New { year, day } => match scaffold::new_day(root, year, day) {
+            Ok(directory) => println!("Created {}", directory.display()),
+            Err(error) => exit_with(&format!("Could not create {} day {}: {}", year, day, error)),
+        },
+        Command::Run { year, day, args } => {
+            let days = match day {
+                Some(day) => vec![day],
+                None => runner::days_in_year(root, year)
+                    .unwrap_or_else(|error| exit_with(&format!("Could not list {}: {}", year, error))),
+            };
+
+            for day in days {
+                eprintln!("{} day {}", year, day);
+                match runner::run_day(root, year, day, &args) {
+                    Ok(status) if status.success() => {}
+                    Ok(status) => eprintln!("{} day {} exited with {}", year, day, status),
+                    Err(error) => exit_with(&format!("Could not run {} day {}: {}", year, day, error)),
+                }
+            }
+        }
+    }
+}
+
+fn exit_with(message: &str) -> ! {
+    eprintln!("{}", message);
+    process::exit(1);
+}
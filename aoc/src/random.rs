@@ -0,0 +1,18 @@
+/// A linear congruential generator, so generated test and benchmark inputs are the same on
+/// every run without pulling in a random number crate.
+pub struct Lcg {
+    state: u64,
+}
+
+impl Lcg {
+    pub fn new(seed: u64) -> Lcg {
+        Lcg { state: seed }
+    }
+
+    /// The next value. The high bits are far more random than the low ones, so shift
+    /// before reducing.
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+        self.state
+    }
+}
@@ -0,0 +1,58 @@
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::process::{Command, ExitStatus};
+
+pub fn day_directory(root: &Path, year: u16, day: u8) -> PathBuf {
+    root.join(year.to_string()).join(format!("day_{}", day))
+}
+
+/// Lists the days that exist for a year, in calendar order.
+pub fn days_in_year(root: &Path, year: u16) -> io::Result<Vec<u8>> {
+    let mut days: Vec<u8> = fs::read_dir(root.join(year.to_string()))?
+        .filter_map(|entry| entry.ok())
+        .filter(|entry| entry.path().join("Cargo.toml").exists())
+        .filter_map(|entry| {
+            entry
+                .file_name()
+                .to_str()
+                .and_then(|name| name.strip_prefix("day_"))
+                .and_then(|day| day.parse().ok())
+        })
+        .collect();
+
+    days.sort();
+    Ok(days)
+}
+
+/// Builds and runs a day's binary from inside its directory so it picks up that year's `input.txt`.
+pub fn run_day(root: &Path, year: u16, day: u8, args: &[String]) -> io::Result<ExitStatus> {
+    let directory = day_directory(root, year, day);
+    if !directory.join("Cargo.toml").exists() {
+        return Err(io::Error::new(
+            io::ErrorKind::NotFound,
+            format!("{} has no day {}", year, day),
+        ));
+    }
+
+    Command::new(env!("CARGO"))
+        .args(["run", "--release", "--quiet", "--"])
+        .args(args)
+        .current_dir(directory)
+        .status()
+}
+
+#[test]
+fn days_are_listed_in_calendar_order() {
+    let root = std::env::temp_dir().join(format!("aoc_runner_{}", std::process::id()));
+    for day in [10, 2, 1] {
+        let directory = day_directory(&root, 2024, day);
+        fs::create_dir_all(&directory).unwrap();
+        fs::write(directory.join("Cargo.toml"), "").unwrap();
+    }
+    fs::create_dir_all(root.join("2024").join("notes")).unwrap();
+
+    assert_eq!(vec![1, 2, 10], days_in_year(&root, 2024).unwrap());
+
+    fs::remove_dir_all(root).unwrap();
+}
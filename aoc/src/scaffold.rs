@@ -0,0 +1,166 @@
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use crate::runner;
+
+const CARGO_TEMPLATE: &str = r#"[package]
+name = "day_{day}"
+version = "0.1.0"
+edition = "2021"
+
+[dependencies]
+aoc = { path = "../../aoc" }
+"#;
+
+const MAIN_TEMPLATE: &str = r#"use aoc::{Answer, Solution};
+
+struct Day{day};
+
+impl Solution for Day{day} {
+    const YEAR: u16 = {year};
+    const DAY: u8 = {day};
+
+    fn part_one(input: &str) -> impl Into<Answer> {
+        part_one(input)
+    }
+
+    fn part_two(input: &str) -> Option<impl Into<Answer>> {
+        Some(part_two(input))
+    }
+}
+
+fn main() {
+    aoc::run::<Day{day}>();
+}
+
+fn part_one(input: &str) -> usize {
+    parse_input(input).len()
+}
+
+fn part_two(input: &str) -> usize {
+    parse_input(input).len()
+}
+
+fn parse_input(input: &str) -> Vec<&str> {
+    input.lines().collect()
+}
+
+#[test]
+fn small_input() {
+    let input = include_str!("../example.txt");
+
+    assert_eq!(0, part_one(input));
+    assert_eq!(0, part_two(input));
+}
+"#;
+
+const UTF_16_BOM: [u8; 2] = [0xff, 0xfe];
+
+pub fn new_day(root: &Path, year: u16, day: u8) -> io::Result<PathBuf> {
+    let directory = runner::day_directory(root, year, day);
+    if directory.exists() {
+        return Err(io::Error::new(
+            io::ErrorKind::AlreadyExists,
+            format!("{} already exists", directory.display()),
+        ));
+    }
+
+    fs::create_dir_all(directory.join("src"))?;
+    fs::write(directory.join("Cargo.toml"), fill_template(CARGO_TEMPLATE, year, day))?;
+    fs::write(directory.join("src").join("main.rs"), fill_template(MAIN_TEMPLATE, year, day))?;
+    fs::write(directory.join("example.txt"), "")?;
+    fs::write(directory.join("input.txt"), "")?;
+
+    add_answer_placeholder(&root.join(year.to_string()).join("README.md"), year, day)?;
+
+    Ok(directory)
+}
+
+fn fill_template(template: &str, year: u16, day: u8) -> String {
+    template
+        .replace("{year}", &year.to_string())
+        .replace("{day}", &day.to_string())
+}
+
+/// Adds an empty `Day N - ` line under the year's existing answers, keeping the README's encoding.
+fn add_answer_placeholder(readme: &Path, year: u16, day: u8) -> io::Result<()> {
+    if !readme.exists() {
+        return fs::write(readme, format!("# {}\n\nDay {} - \n", year, day));
+    }
+
+    let bytes = fs::read(readme)?;
+    let is_utf_16 = bytes.starts_with(&UTF_16_BOM);
+
+    let contents = if is_utf_16 {
+        let units: Vec<u16> = bytes[2..]
+            .chunks_exact(2)
+            .map(|pair| u16::from_le_bytes([pair[0], pair[1]]))
+            .collect();
+        String::from_utf16(&units).map_err(|error| io::Error::new(io::ErrorKind::InvalidData, error))?
+    } else {
+        String::from_utf8(bytes).map_err(|error| io::Error::new(io::ErrorKind::InvalidData, error))?
+    };
+
+    let updated = insert_answer_line(&contents, day);
+
+    if is_utf_16 {
+        let mut encoded = UTF_16_BOM.to_vec();
+        updated
+            .encode_utf16()
+            .for_each(|unit| encoded.extend_from_slice(&unit.to_le_bytes()));
+        fs::write(readme, encoded)
+    } else {
+        fs::write(readme, updated)
+    }
+}
+
+fn insert_answer_line(contents: &str, day: u8) -> String {
+    let line_ending = if contents.contains("\r\n") { "\r\n" } else { "\n" };
+    let mut lines: Vec<String> = contents.split(line_ending).map(String::from).collect();
+    let new_line = format!("Day {} - ", day);
+
+    match lines.iter().rposition(|line| line.starts_with("Day ")) {
+        Some(index) => {
+            if !lines[index].trim_end().ends_with('\\') {
+                lines[index] = format!("{} \\", lines[index].trim_end());
+            }
+            lines.insert(index + 1, new_line);
+        }
+        None => lines.push(new_line),
+    }
+
+    lines.join(line_ending)
+}
+
+#[test]
+fn answer_line_is_added_after_the_last_day() {
+    let readme = "# advent_of_code_24\r\n\r\nDay 1 - Part One: 1 \\\r\nDay 2 - Part One: 2\r\n\r\n";
+
+    assert_eq!(
+        "# advent_of_code_24\r\n\r\nDay 1 - Part One: 1 \\\r\nDay 2 - Part One: 2 \\\r\nDay 3 - \r\n\r\n",
+        insert_answer_line(readme, 3)
+    );
+}
+
+#[test]
+fn new_day_creates_the_standard_structure() {
+    let root = std::env::temp_dir().join(format!("aoc_scaffold_{}", std::process::id()));
+    fs::create_dir_all(root.join("2024")).unwrap();
+    fs::write(root.join("2024").join("README.md"), "Day 1 - \n").unwrap();
+
+    let directory = new_day(&root, 2024, 20).unwrap();
+
+    assert!(directory.join("example.txt").exists());
+    assert!(directory.join("input.txt").exists());
+    assert!(fs::read_to_string(directory.join("Cargo.toml")).unwrap().contains("name = \"day_20\""));
+    assert!(fs::read_to_string(directory.join("src").join("main.rs")).unwrap().contains("const DAY: u8 = 20;"));
+    assert_eq!("Day 1 - \\\nDay 20 - \n", fs::read_to_string(root.join("2024").join("README.md")).unwrap());
+    assert!(new_day(&root, 2024, 20).is_err());
+
+    new_day(&root, 2025, 1).unwrap();
+    assert!(root.join("2025").join("day_1").join("src").join("main.rs").exists());
+    assert_eq!("# 2025\n\nDay 1 - \n", fs::read_to_string(root.join("2025").join("README.md")).unwrap());
+
+    fs::remove_dir_all(root).unwrap();
+}